@@ -0,0 +1,152 @@
+use std::{fs, hint, path::PathBuf, process, time::{Duration, Instant}};
+use aoc::days;
+use common::{input::InputSource, params::Params, Part, Solver};
+
+pub struct BenchArgs {
+    day: Option<u32>, // None means all days
+    iterations: u32,
+    output_path: PathBuf
+}
+
+// Timing summary for one stage (parsing or a part) of one day
+struct StageStats {
+    day: u32,
+    stage: String,
+    mean: Duration,
+    min: Duration,
+    stddev: Duration
+}
+
+impl StageStats {
+    fn from_samples(day: u32, stage: &str, samples: &[Duration]) -> StageStats {
+        let n = samples.len() as f64;
+        let mean_secs = samples.iter().map(|s| s.as_secs_f64()).sum::<f64>() / n;
+        let variance = samples.iter().map(|s| (s.as_secs_f64() - mean_secs).powi(2)).sum::<f64>() / n;
+        return StageStats {
+            day: day,
+            stage: String::from(stage),
+            mean: Duration::from_secs_f64(mean_secs),
+            min: *samples.iter().min().unwrap(),
+            stddev: Duration::from_secs_f64(variance.sqrt())
+        };
+    }
+}
+
+pub fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let mut day_arg = None;
+    let mut iterations = 10;
+    let mut output_path = PathBuf::from("bench.json");
+
+    let mut arg_iter = args.iter();
+    while let Some(arg) = arg_iter.next() {
+        if arg == "--iterations" || arg == "-n" {
+            let n_str = arg_iter.next().ok_or("--iterations requires a value")?;
+            iterations = match n_str.parse::<u32>() {
+                Ok(n) if n > 0 => n,
+                _ => return Err(format!("Invalid iteration count '{n_str}'"))
+            };
+        }
+        else if arg == "--output" || arg == "-o" {
+            output_path = PathBuf::from(arg_iter.next().ok_or("--output requires a value")?);
+        }
+        else if day_arg.is_none() {
+            day_arg = Some(arg);
+        }
+        else {
+            return Err(format!("Unexpected argument '{arg}'"));
+        }
+    }
+
+    let day = match day_arg.map(|d| d.as_str()) {
+        None | Some("all") => None,
+        Some(d) => match d.parse::<u32>() {
+            Ok(n) if (1..=days::NUM_DAYS).contains(&n) => Some(n),
+            _ => return Err(format!("Invalid day '{d}', expected 1-{} or 'all'", days::NUM_DAYS))
+        }
+    };
+    return Ok(BenchArgs { day: day, iterations: iterations, output_path: output_path });
+}
+
+// Times parsing and each part separately over the given number of iterations, after one untimed warm-up run
+fn bench_day(solver: &dyn Solver, input: &str, iterations: u32) -> Result<Vec<StageStats>, String> {
+    solver.parse(input).map_err(|e| format!("invalid input: {e}"))?;
+
+    let mut parse_samples = Vec::new();
+    let mut part_samples = vec![Vec::new(); Part::ALL.len()];
+    for i in 0..(iterations + 1) {
+        let start = Instant::now();
+        let parsed = hint::black_box(solver.parse(input).unwrap());
+        let parse_time = start.elapsed();
+
+        let mut part_times = Vec::new();
+        for part in Part::ALL {
+            let start = Instant::now();
+            let answer = hint::black_box(solver.solve(&*parsed, part, &Params::new()));
+            if i == 0 {
+                answer.map_err(|e| format!("part {part}: {e}"))?;
+            }
+            part_times.push(start.elapsed());
+        }
+
+        if i > 0 {
+            parse_samples.push(parse_time);
+            for (samples, time) in part_samples.iter_mut().zip(part_times) {
+                samples.push(time);
+            }
+        }
+    }
+
+    let mut stats = vec![StageStats::from_samples(solver.day(), "parse", &parse_samples)];
+    for (part, samples) in Part::ALL.iter().zip(&part_samples) {
+        stats.push(StageStats::from_samples(solver.day(), &format!("part{part}"), samples));
+    }
+    return Ok(stats);
+}
+
+pub fn bench(args: &BenchArgs) {
+    let bench_days: Vec<u32> = match args.day {
+        Some(day) => vec![day],
+        None => (1..(days::NUM_DAYS + 1)).collect()
+    };
+
+    println!("Day  Stage  {:>12}  {:>12}  {:>12}", "Mean", "Min", "Stddev");
+    let mut all_stats = Vec::new();
+    for day in bench_days {
+        let input = match InputSource::from_arg(day, None).read() {
+            Ok(s) => s,
+            Err(e) => {
+                eprintln!("Skipping day {day:02}: could not read input: {e}");
+                continue;
+            }
+        };
+        let stats = match bench_day(days::get(day), &input, args.iterations) {
+            Ok(s) => s,
+            Err(e) => {
+                eprintln!("Skipping day {day:02}: {e}");
+                continue;
+            }
+        };
+        for s in &stats {
+            println!("{:02}   {:<5}  {:>12.2?}  {:>12.2?}  {:>12.2?}", s.day, s.stage, s.mean, s.min, s.stddev);
+        }
+        all_stats.extend(stats);
+    }
+
+    if let Err(e) = fs::write(&args.output_path, to_json(&all_stats, args.iterations)) {
+        eprintln!("Error: could not write {}: {e}", args.output_path.display());
+        process::exit(1);
+    }
+    println!("Wrote results to {}", args.output_path.display());
+}
+
+// One object per stage, with times in nanoseconds, so that runs from different commits can be diffed or loaded elsewhere
+fn to_json(stats: &[StageStats], iterations: u32) -> String {
+    let mut json = format!("{{\n  \"iterations\": {iterations},\n  \"results\": [\n");
+    for (i, s) in stats.iter().enumerate() {
+        json += &format!("    {{\"day\": {}, \"stage\": \"{}\", \"mean_ns\": {}, \"min_ns\": {}, \"stddev_ns\": {}}}",
+                         s.day, s.stage, s.mean.as_nanos(), s.min.as_nanos(), s.stddev.as_nanos());
+        json += if i + 1 < stats.len() { ",\n" } else { "\n" };
+    }
+    json += "  ]\n}\n";
+    return json;
+}
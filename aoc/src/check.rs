@@ -0,0 +1,48 @@
+use std::process;
+use aoc::cross_check::{self, CrossCheck};
+use common::input;
+use crate::RunArgs;
+
+// Runs the cross-check for the requested day, or for every day that has one when running all days
+// Days without an input are skipped when running all days, like `aoc run all`
+// Exits with status 1 if any comparison disagrees or an input cannot be read or parsed
+pub fn run_cross_checks(run_args: &RunArgs) {
+    let checks: Vec<&CrossCheck> = match run_args.day {
+        Some(day) => vec![cross_check::get(day).unwrap()],
+        None => cross_check::CROSS_CHECKS.iter().collect()
+    };
+
+    let mut failed = false;
+    for check in checks {
+        let input_str = match input::load(check.day, run_args.input_path.as_deref()) {
+            Ok(s) => s,
+            Err(e) => {
+                eprintln!("Day {:02}: could not read input: {e}", check.day);
+                failed |= run_args.day.is_some();
+                continue;
+            }
+        };
+        let report = match (check.check)(&input_str) {
+            Ok(r) => r,
+            Err(e) => {
+                eprintln!("Day {:02}: invalid input: {e}", check.day);
+                failed = true;
+                continue;
+            }
+        };
+
+        let disagreements = report.disagreements();
+        println!("Day {:02}, {}: {} of {} comparisons agree", check.day, check.description,
+                 report.comparisons.len() - disagreements.len(), report.comparisons.len());
+        for comparison in &disagreements {
+            println!("  Mismatch on {}: fast {}, slow {}", comparison.description, comparison.fast, comparison.slow);
+        }
+        for skipped in &report.skipped {
+            println!("  Skipped {skipped}");
+        }
+        failed |= !disagreements.is_empty();
+    }
+    if failed {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,151 @@
+// Runs the slow, obviously correct algorithm of a day against its fast one on the same input, so that the fast
+// paths can be trusted on inputs that only the fast ones can solve
+// Each check rewrites the input into a form both algorithms can take: day 5's seeds become ranges of one seed,
+// day 12's rows are compared one at a time, folded and unfolded, and day 18's plan is encoded as colors
+
+use common::{Direction, ParseError, Solution};
+use day05::{Almanac, Day05};
+use day12::Day12;
+use day18::{Day18, DigInstruction};
+
+// Inputs larger than these are left to the fast algorithm alone, since the slow one would take too long
+pub const MAX_DIRECT_SEEDS: i64 = 1_000_000;
+pub const MAX_BRUTE_FORCE_UNKNOWNS: usize = 16;
+pub const MAX_FLOOD_FILL_STEPS: i64 = 10_000;
+
+// The answers of both algorithms for one rewriting of the input
+#[derive(Debug, PartialEq, Eq)]
+pub struct Comparison {
+    pub description: String,
+    pub fast: String,
+    pub slow: String
+}
+
+impl Comparison {
+    fn new(description: String, fast: impl ToString, slow: impl ToString) -> Comparison {
+        return Comparison { description: description, fast: fast.to_string(), slow: slow.to_string() };
+    }
+
+    pub fn agrees(&self) -> bool {
+        return self.fast == self.slow;
+    }
+}
+
+// Everything a check compared, and what it left out as too large for the slow algorithm
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    pub comparisons: Vec<Comparison>,
+    pub skipped: Vec<String>
+}
+
+impl Report {
+    pub fn disagreements(&self) -> Vec<&Comparison> {
+        return self.comparisons.iter().filter(|c| !c.agrees()).collect();
+    }
+}
+
+pub struct CrossCheck {
+    pub day: u32,
+    pub description: &'static str,
+    pub check: fn(&str) -> Result<Report, ParseError>
+}
+
+pub const CROSS_CHECKS: [CrossCheck; 3] = [
+    CrossCheck { day: 5, description: "direct seed mapping against the range mapping", check: check_day05 },
+    CrossCheck { day: 12, description: "enumerating every arrangement against the memoized count", check: check_day12 },
+    CrossCheck { day: 18, description: "flood fill against the shoelace formula", check: check_day18 }
+];
+
+pub fn get(day: u32) -> Option<&'static CrossCheck> {
+    return CROSS_CHECKS.iter().find(|c| c.day == day);
+}
+
+// An answer, or the error that stopped an algorithm, which then disagrees with the other unless it failed the same way
+fn shown<T: ToString, E: ToString>(answer: Result<T, E>) -> String {
+    return match answer {
+        Ok(a) => a.to_string(),
+        Err(e) => e.to_string()
+    };
+}
+
+// Part 1 maps each seed directly and part 2 maps whole ranges of seeds at once, so part 2 must find the same
+// minimum when each seed is written as a range of one, and part 1 the same as part 2 when every seed of the ranges
+// is listed, if there are few enough of them
+fn check_day05(input: &str) -> Result<Report, ParseError> {
+    let almanac = Day05::parse(input)?;
+    let mut report = Report::default();
+
+    let single_seeds = almanac.seeds.iter().flat_map(|s| [*s, 1]).collect();
+    let single_seed_almanac = Almanac { seeds: single_seeds, maps: almanac.maps.clone() };
+    report.comparisons.push(Comparison::new(String::from("seeds as ranges of one seed"),
+                                            shown(day05::part2(&single_seed_almanac)), shown(day05::part1(&almanac))));
+
+    let ranges: Vec<(i64, i64)> = almanac.seeds.chunks_exact(2).map(|r| (r[0], r[1])).collect();
+    let num_seeds = ranges.iter().try_fold(0, |total: i64, r| total.checked_add(r.1.max(0)));
+    if ranges.iter().any(|(start, size)| start.checked_add(*size).is_none()) {
+        report.skipped.push(String::from("seed ranges listed seed by seed, as a range runs past the largest number"));
+    }
+    else if num_seeds.is_none_or(|n| n > MAX_DIRECT_SEEDS) {
+        let shown_seeds = num_seeds.map_or(String::from("more than 2^63"), |n| n.to_string());
+        report.skipped.push(format!("seed ranges listed seed by seed, as they hold {shown_seeds} seeds"));
+    }
+    else {
+        let all_seeds = ranges.iter().flat_map(|(start, size)| *start..(start + size)).collect();
+        let all_seed_almanac = Almanac { seeds: all_seeds, maps: almanac.maps.clone() };
+        report.comparisons.push(Comparison::new(String::from("seed ranges listed seed by seed"),
+                                                shown(day05::part2(&almanac)), shown(day05::part1(&all_seed_almanac))));
+    }
+    return Ok(report);
+}
+
+// Part 1 tries every assignment of the unknown springs while count_arrangements memoizes, so they must agree on
+// every row, and on every row unfolded twice that has few enough unknown springs to enumerate
+fn check_day12(input: &str) -> Result<Report, ParseError> {
+    let records = Day12::parse(input)?;
+    let mut report = Report::default();
+    let mut num_too_large = 0;
+    for (i, (condition_str, contiguous_groups)) in records.iter().enumerate() {
+        for copies in [1, 2] {
+            let (condition_data, condition_groups) = day12::unfold(condition_str, contiguous_groups, copies);
+            let num_unknown = condition_data.iter().filter(|ch| **ch == '?').count();
+            if num_unknown > MAX_BRUTE_FORCE_UNKNOWNS {
+                num_too_large += 1;
+                continue;
+            }
+            let description = if copies == 1 { format!("row {}", i + 1) } else { format!("row {} unfolded twice", i + 1) };
+            let fast = shown(day12::count_arrangements(&condition_data, &condition_groups));
+            let slow = shown(day12::part1(&vec![(condition_data, condition_groups)]));
+            report.comparisons.push(Comparison::new(description, fast, slow));
+        }
+    }
+    if num_too_large > 0 {
+        report.skipped.push(format!("{num_too_large} rows, folded or unfolded twice, with more than {MAX_BRUTE_FORCE_UNKNOWNS} unknown springs"));
+    }
+    return Ok(report);
+}
+
+// Part 2 finds the area with the shoelace formula from the plan hidden in the colors, so it must give part 1's
+// flood filled area when part 1's plan is encoded as colors, and part 1 must give part 2's area when the plan in
+// the colors is small enough to draw
+fn check_day18(input: &str) -> Result<Report, ParseError> {
+    let plan = Day18::parse(input)?;
+    let mut report = Report::default();
+
+    let encoded_plan = plan.iter().map(|i| {
+        return DigInstruction { dir: i.dir, step_count: i.step_count, color_dir: i.dir, color_step_count: i.step_count as i64 };
+    }).collect();
+    report.comparisons.push(Comparison::new(String::from("plan encoded as colors"), shown(day18::part2(&encoded_plan)), day18::part1(&plan)));
+
+    let decoded: Vec<(Direction, i64)> = plan.iter().map(|i| (i.color_dir, i.color_step_count)).collect();
+    let num_steps: i64 = decoded.iter().map(|d| d.1).sum();
+    if num_steps > MAX_FLOOD_FILL_STEPS {
+        report.skipped.push(format!("plan decoded from the colors, as it digs {num_steps} meters"));
+    }
+    else {
+        let decoded_plan = decoded.iter().map(|(dir, step_count)| {
+            return DigInstruction { dir: *dir, step_count: *step_count as usize, color_dir: Direction::Right, color_step_count: 0 };
+        }).collect();
+        report.comparisons.push(Comparison::new(String::from("plan decoded from the colors"), shown(day18::part2(&plan)), day18::part1(&decoded_plan)));
+    }
+    return Ok(report);
+}
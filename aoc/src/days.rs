@@ -0,0 +1,36 @@
+use common::Solver;
+
+pub const NUM_DAYS: u32 = 25;
+
+// Solvers for every day, indexed by day - 1
+pub const SOLVERS: [&dyn Solver; NUM_DAYS as usize] = [
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+    &day21::Day21,
+    &day22::Day22,
+    &day23::Day23,
+    &day24::Day24,
+    &day25::Day25
+];
+
+pub fn get(day: u32) -> &'static dyn Solver {
+    return SOLVERS[(day - 1) as usize];
+}
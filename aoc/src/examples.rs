@@ -0,0 +1,84 @@
+use std::process;
+use aoc::days;
+use common::{input, Part};
+use crate::RunArgs;
+
+// Runs the requested day, or every day, on each of its embedded examples that applies to the requested parts,
+// with the example's parameters, and compares the answers with the ones from the puzzle text
+// Exits with status 1 if any answer differs or cannot be solved, or an example is invalid
+pub fn run_examples(run_args: &RunArgs) {
+    let day_range = match run_args.day {
+        Some(day) => day..(day + 1),
+        None => 1..(days::NUM_DAYS + 1)
+    };
+
+    let mut num_ok = 0;
+    let mut num_unknown = 0;
+    let mut num_failed = 0;
+    for day in day_range {
+        let solver = days::get(day);
+        if solver.examples().is_empty() {
+            println!("Day {day:02}: no examples");
+            continue;
+        }
+        for example in solver.examples() {
+            let parts: Vec<Part> = example.parts().into_iter().filter(|p| run_args.parts.contains(p)).collect();
+            if parts.is_empty() {
+                continue;
+            }
+            let params = match example.params() {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Day {day:02} {}: invalid answers: {e}", example.name);
+                    num_failed += 1;
+                    continue;
+                }
+            };
+            let parsed = match solver.parse(&input::normalize(example.input)) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Day {day:02} {}: invalid input: {e}", example.name);
+                    num_failed += 1;
+                    continue;
+                }
+            };
+
+            for part in parts {
+                let answer = match solver.solve(&*parsed, part, &params) {
+                    Ok(a) => a.to_string(),
+                    Err(e) => {
+                        println!("Day {day:02} Part {part} {}: FAILED, {e}", example.name);
+                        num_failed += 1;
+                        continue;
+                    }
+                };
+                match example.expected_for(part) {
+                    Ok(Some(expected)) if expected == answer => {
+                        println!("Day {day:02} Part {part} {}: {answer} (ok)", example.name);
+                        num_ok += 1;
+                    },
+                    Ok(Some(expected)) => {
+                        println!("Day {day:02} Part {part} {}: {answer} (MISMATCH, expected {expected})", example.name);
+                        num_failed += 1;
+                    },
+                    Ok(None) => {
+                        println!("Day {day:02} Part {part} {}: {answer} (no known answer)", example.name);
+                        num_unknown += 1;
+                    },
+                    Err(e) => {
+                        println!("Day {day:02} Part {part} {}: invalid answers: {e}", example.name);
+                        num_failed += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    if run_args.day.is_none() {
+        println!();
+        println!("{num_ok} ok, {num_failed} failed, {num_unknown} without a known answer");
+    }
+    if num_failed > 0 {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,57 @@
+use std::{path::PathBuf, process};
+use aoc::{days, provider::{self, Fetcher, HttpFetcher, InputCache, InputProvider}};
+
+pub struct FetchArgs {
+    day: u32,
+    year: u32,
+    cache_dir: PathBuf
+}
+
+pub fn parse_fetch_args(args: &[String]) -> Result<FetchArgs, String> {
+    let mut day_arg = None;
+    let mut year = provider::YEAR;
+    let mut cache_dir = PathBuf::from(provider::DEFAULT_CACHE_DIR);
+
+    let mut arg_iter = args.iter();
+    while let Some(arg) = arg_iter.next() {
+        if arg == "--year" {
+            let year_str = arg_iter.next().ok_or("--year requires a value")?;
+            year = match year_str.parse::<u32>() {
+                Ok(y) if y >= 2015 => y,
+                _ => return Err(format!("Invalid year '{year_str}'"))
+            };
+        }
+        else if arg == "--cache" {
+            cache_dir = PathBuf::from(arg_iter.next().ok_or("--cache requires a value")?);
+        }
+        else if day_arg.is_none() {
+            day_arg = Some(arg);
+        }
+        else {
+            return Err(format!("Unexpected argument '{arg}'"));
+        }
+    }
+
+    let day = match day_arg.map(|d| d.as_str()) {
+        None => return Err(String::from("Missing day")),
+        Some(d) => match d.parse::<u32>() {
+            Ok(n) if (1..=days::NUM_DAYS).contains(&n) => n,
+            _ => return Err(format!("Invalid day '{d}', expected 1-{}", days::NUM_DAYS))
+        }
+    };
+    return Ok(FetchArgs { day: day, year: year, cache_dir: cache_dir });
+}
+
+pub fn fetch(args: &FetchArgs) {
+    let fetcher = provider::session_from_env().map(|session| {
+        return Box::new(HttpFetcher::new(&provider::base_url_from_env(), &session)) as Box<dyn Fetcher>;
+    });
+    let provider = InputProvider::new(InputCache::new(&args.cache_dir), fetcher);
+    match provider.get(args.year, args.day) {
+        Ok(input_str) => print!("{input_str}"),
+        Err(e) => {
+            eprintln!("Error: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::{fs, path::PathBuf, process};
+use aoc::generate;
+
+pub struct GenArgs {
+    day: u32,
+    size: Option<usize>, // None means about the size of a real input
+    seed: u64,
+    output_path: Option<PathBuf>
+}
+
+pub fn parse_gen_args(args: &[String]) -> Result<GenArgs, String> {
+    let mut day_arg = None;
+    let mut size = None;
+    let mut seed = 0;
+    let mut output_path = None;
+
+    let mut arg_iter = args.iter();
+    while let Some(arg) = arg_iter.next() {
+        if arg == "--size" || arg == "-s" {
+            let size_str = arg_iter.next().ok_or("--size requires a value")?;
+            size = match size_str.parse::<usize>() {
+                Ok(n) if n > 0 => Some(n),
+                _ => return Err(format!("Invalid size '{size_str}'"))
+            };
+        }
+        else if arg == "--seed" {
+            let seed_str = arg_iter.next().ok_or("--seed requires a value")?;
+            seed = seed_str.parse::<u64>().map_err(|_| format!("Invalid seed '{seed_str}'"))?;
+        }
+        else if arg == "--output" || arg == "-o" {
+            output_path = Some(PathBuf::from(arg_iter.next().ok_or("--output requires a value")?));
+        }
+        else if day_arg.is_none() {
+            day_arg = Some(arg);
+        }
+        else {
+            return Err(format!("Unexpected argument '{arg}'"));
+        }
+    }
+
+    let days: Vec<String> = generate::GENERATORS.iter().map(|g| g.day.to_string()).collect();
+    let day = match day_arg.map(|d| d.as_str()) {
+        None => return Err(String::from("Missing day")),
+        Some(d) => match d.parse::<u32>() {
+            Ok(n) if generate::get(n).is_some() => n,
+            _ => return Err(format!("No generator for day '{d}', expected one of {}", days.join(", ")))
+        }
+    };
+    return Ok(GenArgs { day: day, size: size, seed: seed, output_path: output_path });
+}
+
+// With --output, answers known from how the input was built are written next to it in the fixture format,
+// so that the pair can be dropped into dayNN/fixtures
+pub fn gen(args: &GenArgs) {
+    let generator = generate::get(args.day).unwrap();
+    let size = args.size.unwrap_or(generator.default_size);
+    let generated = generate::generate(args.day, size, args.seed).unwrap();
+    let Some(output_path) = &args.output_path else {
+        print!("{}", generated.input);
+        return;
+    };
+
+    let mut answers_str = format!("# Generated by `aoc gen {} --size {size} --seed {}`\n", args.day, args.seed);
+    for (part, answer) in &generated.answers {
+        answers_str += &format!("part{part}: {answer}\n");
+    }
+    let answers_path = output_path.with_extension("answers");
+    for (path, contents) in [(output_path, &generated.input), (&answers_path, &answers_str)] {
+        if let Err(e) = fs::write(path, contents) {
+            eprintln!("Error: could not write {}: {e}", path.display());
+            process::exit(1);
+        }
+    }
+}
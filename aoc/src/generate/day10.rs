@@ -0,0 +1,108 @@
+use common::Part;
+use super::{Generated, Rng};
+
+const NEIGHBORS: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+// A random tree of thin corridors, drawn with its branches four tiles apart, has a ring of tiles around it that forms
+// a single loop, since branches never get close enough for their rings to touch
+// The loop encloses exactly the tree's tiles, so both answers are known, and every tile off the loop is filled with
+// random pipes to make finding it harder
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let branches = size.max(1);
+    let width = 4 * branches + 1;
+    let mut is_tree = vec![vec![false; width]; width];
+
+    // Random spanning tree of the branch points, grown from a random point by picking frontier edges at random
+    let mut in_tree = vec![vec![false; branches]; branches];
+    let first = (rng.range(0, branches), rng.range(0, branches));
+    in_tree[first.0][first.1] = true;
+    is_tree[4 * first.0 + 2][4 * first.1 + 2] = true;
+    let mut frontier = Vec::new();
+    let add_edges = |frontier: &mut Vec<((usize, usize), (usize, usize))>, (r, c): (usize, usize)| {
+        for (dr, dc) in NEIGHBORS {
+            let (nr, nc) = (r as isize + dr, c as isize + dc);
+            if nr >= 0 && nc >= 0 && (nr as usize) < branches && (nc as usize) < branches {
+                frontier.push(((r, c), (nr as usize, nc as usize)));
+            }
+        }
+    };
+    add_edges(&mut frontier, first);
+    while !frontier.is_empty() {
+        let ((r, c), (nr, nc)) = frontier.swap_remove(rng.range(0, frontier.len()));
+        if in_tree[nr][nc] {
+            continue;
+        }
+        in_tree[nr][nc] = true;
+        // Draw the corridor between the two branch points, which share a row or a column
+        for tree_row in (4 * r.min(nr) + 2)..(4 * r.max(nr) + 3) {
+            for tree_col in (4 * c.min(nc) + 2)..(4 * c.max(nc) + 3) {
+                is_tree[tree_row][tree_col] = true;
+            }
+        }
+        add_edges(&mut frontier, (nr, nc));
+    }
+
+    // The ring is every tile next to the tree, including diagonally
+    let mut is_ring = vec![vec![false; width]; width];
+    for r in 1..(width - 1) {
+        for c in 1..(width - 1) {
+            is_ring[r][c] = !is_tree[r][c] && (r - 1..r + 2).any(|rr| (c - 1..c + 2).any(|cc| is_tree[rr][cc]));
+        }
+    }
+
+    // Follow the ring from its first tile, each of which has exactly two ring tiles next to it
+    let start = (1, (0..width).find(|c| is_ring[1][*c]).unwrap());
+    let mut loop_tiles = vec![start];
+    let mut prev = start;
+    let mut curr = start;
+    loop {
+        let next = NEIGHBORS.iter().map(|(dr, dc)| ((curr.0 as isize + dr) as usize, (curr.1 as isize + dc) as usize))
+                            .find(|n| is_ring[n.0][n.1] && *n != prev)
+                            .unwrap();
+        if next == start {
+            break;
+        }
+        loop_tiles.push(next);
+        prev = curr;
+        curr = next;
+    }
+
+    let mut grid = vec![vec!['.'; width]; width];
+    for r in 0..width {
+        for c in 0..width {
+            if rng.chance(0.5) {
+                grid[r][c] = ['|', '-', 'L', 'J', '7', 'F'][rng.range(0, 6)];
+            }
+        }
+    }
+    for i in 0..loop_tiles.len() {
+        let before = loop_tiles[(i + loop_tiles.len() - 1) % loop_tiles.len()];
+        let after = loop_tiles[(i + 1) % loop_tiles.len()];
+        let (r, c) = loop_tiles[i];
+        let connects = |(nr, nc): (usize, usize)| (nr < r, nr > r, nc < c, nc > c);
+        let (b, a) = (connects(before), connects(after));
+        let (north, south, west, east) = (b.0 || a.0, b.1 || a.1, b.2 || a.2, b.3 || a.3);
+        grid[r][c] = match (north, south, west, east) {
+            (true, true, _, _) => '|',
+            (_, _, true, true) => '-',
+            (true, _, _, true) => 'L',
+            (true, _, true, _) => 'J',
+            (_, true, true, _) => '7',
+            _ => 'F'
+        };
+    }
+
+    // Tiles next to the start that are not on the loop must not look connected to it
+    let (sr, sc) = loop_tiles[rng.range(0, loop_tiles.len())];
+    grid[sr][sc] = 'S';
+    for (dr, dc) in NEIGHBORS {
+        let (nr, nc) = ((sr as isize + dr) as usize, (sc as isize + dc) as usize);
+        if !is_ring[nr][nc] {
+            grid[nr][nc] = '.';
+        }
+    }
+
+    let input: String = grid.iter().map(|row| row.iter().collect::<String>() + "\n").collect();
+    let num_enclosed = is_tree.iter().flatten().filter(|t| **t).count();
+    return Generated { input: input, answers: vec![(Part::One, (loop_tiles.len() / 2).to_string()), (Part::Two, num_enclosed.to_string())] };
+}
@@ -0,0 +1,36 @@
+use super::{Generated, Rng};
+
+// Each record is a random row of springs, up to 20 long like the real ones, with about half of its conditions hidden
+// Building the row first means every record has at least one arrangement
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    for _ in 0..size {
+        let row_len = rng.range(5, 21);
+        let mut row = vec!['.'; row_len];
+        let mut groups = Vec::new();
+        let mut pos = rng.range(0, 3);
+        loop {
+            let group_len = rng.range(1, 6);
+            if pos + group_len > row_len {
+                break;
+            }
+            for i in pos..(pos + group_len) {
+                row[i] = '#';
+            }
+            groups.push(group_len.to_string());
+            pos += group_len + rng.range(1, 4);
+        }
+        if groups.is_empty() {
+            row[rng.range(0, row_len)] = '#';
+            groups.push(String::from("1"));
+        }
+
+        for c in row.iter_mut() {
+            if rng.chance(0.5) {
+                *c = '?';
+            }
+        }
+        input += &format!("{} {}\n", row.iter().collect::<String>(), groups.join(","));
+    }
+    return Generated { input: input, answers: Vec::new() };
+}
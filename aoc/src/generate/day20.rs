@@ -0,0 +1,78 @@
+use common::Part;
+use super::{Generated, Rng};
+
+const COUNTER_BITS: u32 = 12;
+
+// Broadcaster outputs each start a 12 bit counter of flip-flops, like the real networks
+// A conjunction watches the bits that are set in the counter's period and resets the counter when they are all on,
+// which also sends a pulse through an inverter to the conjunction feeding rx
+// Periods are distinct primes, so rx first gets a low pulse after their product, which is known when it fits in 64 bits
+// Outputs are listed in the order the real networks use: each flip-flop reports to the conjunction before carrying to
+// the next bit, and the conjunction sets the unwatched bits before clearing the first one
+// The solver depends on this, since it sends a module's latest state when its turn in the queue comes
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let num_counters = size.max(1);
+    let mut periods: Vec<u64> = Vec::new();
+    let candidates: Vec<u64> = ((1 << (COUNTER_BITS - 1))..(1 << COUNTER_BITS)).filter(|n| is_prime(*n)).collect();
+    if num_counters > candidates.len() {
+        panic!("at most {} counters can have distinct periods", candidates.len());
+    }
+    while periods.len() < num_counters {
+        let period = candidates[rng.range(0, candidates.len())];
+        if !periods.contains(&period) {
+            periods.push(period);
+        }
+    }
+
+    let modules_needed = num_counters * (COUNTER_BITS as usize + 2) + 1;
+    let name_len = if modules_needed * 2 < 26 * 26 { 2 } else { 3 };
+    let mut names = Vec::new();
+    while names.len() < modules_needed {
+        let name: String = (0..name_len).map(|_| (b'a' + rng.range(0, 26) as u8) as char).collect();
+        if name != "rx" && !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    let final_module = names.pop().unwrap();
+
+    let mut lines = Vec::new();
+    let mut starts = Vec::new();
+    for (counter, period) in periods.iter().enumerate() {
+        let counter_names = &names[(counter * (COUNTER_BITS as usize + 2))..((counter + 1) * (COUNTER_BITS as usize + 2))];
+        let (flip_flops, hub, inverter) = (&counter_names[..(COUNTER_BITS as usize)], &counter_names[COUNTER_BITS as usize], &counter_names[COUNTER_BITS as usize + 1]);
+        starts.push(flip_flops[0].clone());
+
+        let mut hub_outputs = Vec::new();
+        for bit in 0..(COUNTER_BITS as usize) {
+            let mut outputs = Vec::new();
+            if period & (1 << bit) != 0 {
+                outputs.push(hub.clone());
+            }
+            else {
+                hub_outputs.push(flip_flops[bit].clone());
+            }
+            if bit + 1 < COUNTER_BITS as usize {
+                outputs.push(flip_flops[bit + 1].clone());
+            }
+            lines.push(format!("%{} -> {}\n", flip_flops[bit], outputs.join(", ")));
+        }
+        rng.shuffle(&mut hub_outputs);
+        hub_outputs.push(flip_flops[0].clone());
+        hub_outputs.insert(rng.range(0, hub_outputs.len() + 1), inverter.clone());
+        lines.push(format!("&{hub} -> {}\n", hub_outputs.join(", ")));
+        lines.push(format!("&{inverter} -> {final_module}\n"));
+    }
+    lines.push(format!("broadcaster -> {}\n", starts.join(", ")));
+    lines.push(format!("&{final_module} -> rx\n"));
+    rng.shuffle(&mut lines);
+
+    let mut answers = Vec::new();
+    if let Some(product) = periods.iter().try_fold(1u64, |a, p| a.checked_mul(*p)) {
+        answers.push((Part::Two, product.to_string()));
+    }
+    return Generated { input: lines.concat(), answers: answers };
+}
+
+fn is_prime(n: u64) -> bool {
+    return n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| !n.is_multiple_of(d));
+}
@@ -0,0 +1,39 @@
+use super::{Generated, Rng};
+
+// Bricks up to 5 cubes long over a 10 by 10 area, like the real snapshot, each placed a little above everything
+// already under it so that none overlap, and listed in random order
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut heights = [[0; 10]; 10];
+    let mut lines = Vec::new();
+    for _ in 0..size {
+        let brick_len = rng.range(1, 6);
+        let axis = rng.range(0, 3);
+        let (x1, y1) = match axis {
+            0 => (rng.range(0, 11 - brick_len), rng.range(0, 10)),
+            1 => (rng.range(0, 10), rng.range(0, 11 - brick_len)),
+            _ => (rng.range(0, 10), rng.range(0, 10))
+        };
+        let (x2, y2) = match axis {
+            0 => (x1 + brick_len - 1, y1),
+            1 => (x1, y1 + brick_len - 1),
+            _ => (x1, y1)
+        };
+
+        let mut base = 0;
+        for x in x1..(x2 + 1) {
+            for y in y1..(y2 + 1) {
+                base = base.max(heights[x][y]);
+            }
+        }
+        let z1 = base + 1 + rng.range(0, 4);
+        let z2 = if axis == 2 { z1 + brick_len - 1 } else { z1 };
+        for x in x1..(x2 + 1) {
+            for y in y1..(y2 + 1) {
+                heights[x][y] = z2;
+            }
+        }
+        lines.push(format!("{x1},{y1},{z1}~{x2},{y2},{z2}\n"));
+    }
+    rng.shuffle(&mut lines);
+    return Generated { input: lines.concat(), answers: Vec::new() };
+}
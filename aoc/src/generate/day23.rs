@@ -0,0 +1,54 @@
+use super::{Generated, Rng};
+
+// A lattice of junctions joined by straight trails of random lengths, entered from the top left and left from the
+// bottom right, like the real maps
+// Every trail has a slope at each end pointing right or down, so the slopes make the trails a DAG
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let num_junctions = size.max(1);
+    let mut cols = vec![rng.range(1, 4)];
+    let mut rows = vec![rng.range(2, 5)];
+    for _ in 1..num_junctions {
+        cols.push(cols.last().unwrap() + rng.range(4, 21));
+        rows.push(rows.last().unwrap() + rng.range(4, 21));
+    }
+    let width = cols.last().unwrap() + rng.range(2, 5);
+    let height = rows.last().unwrap() + rng.range(3, 6);
+
+    let mut grid = vec![vec!['#'; width]; height];
+    for &row in &rows {
+        for col in cols[0]..(cols[num_junctions - 1] + 1) {
+            grid[row][col] = '.';
+        }
+    }
+    for &col in &cols {
+        for row in rows[0]..(rows[num_junctions - 1] + 1) {
+            grid[row][col] = '.';
+        }
+    }
+    for row in 0..rows[0] {
+        grid[row][cols[0]] = '.';
+    }
+    for row in rows[num_junctions - 1]..height {
+        grid[row][cols[num_junctions - 1]] = '.';
+    }
+
+    for &row in &rows {
+        for &col in &cols {
+            if grid[row][col - 1] == '.' {
+                grid[row][col - 1] = '>';
+            }
+            if grid[row][col + 1] == '.' {
+                grid[row][col + 1] = '>';
+            }
+            if grid[row - 1][col] == '.' {
+                grid[row - 1][col] = 'v';
+            }
+            if grid[row + 1][col] == '.' {
+                grid[row + 1][col] = 'v';
+            }
+        }
+    }
+
+    let input: String = grid.iter().map(|row| row.iter().collect::<String>() + "\n").collect();
+    return Generated { input: input, answers: Vec::new() };
+}
@@ -0,0 +1,80 @@
+// Random puzzle inputs of any size, for seeing how the solvers scale beyond the real inputs
+// The same day, size and seed always give the same input
+
+use common::Part;
+
+mod day10;
+mod day12;
+mod day20;
+mod day22;
+mod day23;
+
+// Small, fast generator (SplitMix64), kept here rather than taken from a crate so that seeds give the same
+// inputs on every platform and version
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        return Rng { state: seed };
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        return z ^ (z >> 31);
+    }
+
+    // Uniform in low..high, which must not be empty
+    pub fn range(&mut self, low: usize, high: usize) -> usize {
+        assert!(low < high, "empty range {low}..{high}");
+        return low + ((self.next_u64() as u128 * (high - low) as u128) >> 64) as usize;
+    }
+
+    // True with the given probability
+    pub fn chance(&mut self, probability: f64) -> bool {
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        return unit < probability;
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0, i + 1));
+        }
+    }
+}
+
+// A generated input, with whichever answers are known from how it was built
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub answers: Vec<(Part, String)>
+}
+
+pub struct Generator {
+    pub day: u32,
+    pub size_description: &'static str,
+    pub default_size: usize, // About the size of a real input
+    pub generate: fn(&mut Rng, usize) -> Generated
+}
+
+pub const GENERATORS: [Generator; 5] = [
+    Generator { day: 10, size_description: "loop branches per side of the grid, which is 4 * size + 1 tiles wide", default_size: 35, generate: day10::generate },
+    Generator { day: 12, size_description: "spring records", default_size: 1000, generate: day12::generate },
+    Generator { day: 20, size_description: "counter subnetworks feeding rx", default_size: 4, generate: day20::generate },
+    Generator { day: 22, size_description: "bricks", default_size: 1400, generate: day22::generate },
+    Generator { day: 23, size_description: "junctions per side of the trail map", default_size: 6, generate: day23::generate }
+];
+
+pub fn get(day: u32) -> Option<&'static Generator> {
+    return GENERATORS.iter().find(|g| g.day == day);
+}
+
+// None if there is no generator for the day
+pub fn generate(day: u32, size: usize, seed: u64) -> Option<Generated> {
+    return get(day).map(|g| (g.generate)(&mut Rng::new(seed), size));
+}
@@ -0,0 +1,86 @@
+use std::{collections::BTreeMap, fs, io, path::Path};
+use common::{Answer, Part};
+use toml::{Table, Value};
+
+// Where a user's confirmed answers are kept by default, next to their inputs
+pub const DEFAULT_PATH: &str = "inputs/answers.toml";
+
+// Confirmed answers for one user's inputs, stored as TOML with a table per day:
+//
+//     [day01]
+//     part1 = 54304
+//     part2 = 54418
+//
+// Answers may be written as integers or strings, and are compared as text
+// Only integer answers are written back as integers, so text such as "007" keeps its leading zeros
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Ledger {
+    answers: BTreeMap<(u32, Part), Answer>
+}
+
+impl Ledger {
+    // A missing file is an empty ledger, so that the first verify run can start one
+    pub fn load(path: &Path) -> Result<Ledger, String> {
+        return match fs::read_to_string(path) {
+            Ok(toml_str) => Ledger::parse(&toml_str).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(e) => Err(format!("{}: {e}", path.display()))
+        };
+    }
+
+    pub fn parse(toml_str: &str) -> Result<Ledger, String> {
+        let table: Table = toml_str.parse().map_err(|e: toml::de::Error| e.message().to_string())?;
+        let mut answers = BTreeMap::new();
+        for (day_key, day_value) in &table {
+            let day = day_key.strip_prefix("day").and_then(|d| d.parse::<u32>().ok())
+                                                  .ok_or_else(|| format!("expected a table named dayNN, found '{day_key}'"))?;
+            let Value::Table(day_table) = day_value else {
+                return Err(format!("expected '{day_key}' to be a table"));
+            };
+            for (part_key, answer_value) in day_table {
+                let part = match part_key.as_str() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    _ => return Err(format!("expected part1 or part2 in '{day_key}', found '{part_key}'"))
+                };
+                let answer = match answer_value {
+                    Value::Integer(n) => Answer::Int(*n),
+                    Value::String(s) => Answer::Text(s.clone()),
+                    _ => return Err(format!("expected '{day_key}.{part_key}' to be an integer or a string"))
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+        return Ok(Ledger { answers: answers });
+    }
+
+    pub fn get(&self, day: u32, part: Part) -> Option<&Answer> {
+        return self.answers.get(&(day, part));
+    }
+
+    pub fn record(&mut self, day: u32, part: Part, answer: Answer) {
+        self.answers.insert((day, part), answer);
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut table = Table::new();
+        for ((day, part), answer) in &self.answers {
+            let day_table = table.entry(format!("day{day:02}")).or_insert_with(|| Value::Table(Table::new()));
+            // TOML integers are 64-bit and signed, so wider answers are written as strings
+            let answer_value = match answer {
+                Answer::Int(n) => Value::Integer(*n),
+                Answer::UInt(n) => i64::try_from(*n).map_or_else(|_| Value::String(n.to_string()), Value::Integer),
+                Answer::Wide(n) => i64::try_from(*n).map_or_else(|_| Value::String(n.to_string()), Value::Integer),
+                Answer::Text(s) => Value::String(s.clone())
+            };
+            if let Value::Table(day_table) = day_table {
+                day_table.insert(format!("part{part}"), answer_value);
+            }
+        }
+        return table.to_string();
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        return fs::write(path, self.to_toml());
+    }
+}
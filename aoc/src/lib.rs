@@ -0,0 +1,12 @@
+// Registry of every day's solver, cross-checks between each day's algorithms, the answers ledger, run records, the
+// clients for downloading inputs and submitting answers, and the scaffolding for new days, shared by the aoc binary
+// and its tests
+
+pub mod cross_check;
+pub mod days;
+pub mod generate;
+pub mod ledger;
+pub mod provider;
+pub mod record;
+pub mod scaffold;
+pub mod submission;
@@ -0,0 +1,261 @@
+use std::{env, process, thread, time::Instant};
+use aoc::{days, record::Record};
+use common::{input, log, params::Params, ParseError, Part, Solver};
+
+mod bench;
+mod check;
+mod examples;
+mod fetch;
+mod gen;
+mod new;
+mod run_all;
+mod submit;
+mod verify;
+
+const USAGE: &str = "Usage: aoc run <DAY|all> [--part <1|2>] [--param NAME=VALUE]... [--format <text|json>] [--jobs N] [INPUT]
+       aoc run <DAY|all> --cross-check [INPUT]
+       aoc run <DAY|all> --example [--part <1|2>]
+       aoc verify [--answers FILE] [--record]
+       aoc bench [DAY|all] [--iterations N] [--output FILE]
+       aoc fetch <DAY> [--year YEAR] [--cache DIR]
+       aoc submit <DAY> <PART> [INPUT] [--log FILE] [--answers FILE]
+       aoc new <DAY> [--title TITLE] [--root DIR]
+       aoc gen <DAY> [--size N] [--seed S] [--output FILE]
+
+run     Runs the solver for DAY (1-25) on INPUT. INPUT may be a file path, or `-`
+        to read stdin, and defaults to inputs/dayNN.txt. `aoc run all` runs every
+        day on its default input, N at a time (default one per CPU), and ends
+        with a table of timings, slowest first. --param overrides one of the day's puzzle
+        constants, such as `aoc run 21 --param steps=6`. With --format json,
+        each part is printed as a JSON object on its own line, with fields day,
+        part, answer, elapsed_ns and error. With --cross-check, days 5, 12 and
+        18 instead run their brute force algorithms against the fast ones on
+        the same input, rewritten so that both can take it, and report any
+        answers that differ. With --example, the day instead runs on each of
+        the examples from its puzzle text, which are built into the binary,
+        using the example's constants where they differ from the real
+        puzzle's, and checks the answers given there.
+verify  Runs every day on its default input and checks the answers against the
+        answers file (default inputs/answers.toml). --record adds newly solved
+        parts to the file.
+bench   Times parsing and each part of DAY (default all) on its default input
+        over N iterations (default 10), printing the mean, minimum and standard
+        deviation. The results are also written as JSON to FILE (default
+        bench.json).
+fetch   Prints the input for DAY of YEAR (default 2023), reading it from the
+        cache (default inputs/cache) when it is there and otherwise downloading
+        it with the session token in AOC_SESSION from AOC_BASE_URL (default
+        https://adventofcode.com). Downloads are cached and never repeated.
+submit  Solves PART of DAY on INPUT (default inputs/dayNN.txt) and submits the
+        answer to AOC_BASE_URL with the session token in AOC_SESSION. Every
+        submission is logged to FILE (default inputs/submissions.toml), and
+        answers that were already rejected or that come within a minute of
+        the last submission are not sent. Accepted answers are also added to
+        the answers file.
+new     Creates a dayNN crate with stub parse, part1 and part2 functions and an
+        empty example fixture, in the workspace at DIR (default the current
+        directory), and adds it to the workspace, the aoc runner, aoc bench and
+        the example tests. Days must be added in order.
+gen     Prints a random input for DAY (10, 12, 20, 22 or 23) with the given
+        SIZE, which defaults to about the size of a real input. SIZE counts
+        loop branches per side for day 10, records for day 12, counters for
+        day 20, bricks for day 22 and junctions per side for day 23. The same
+        SEED (default 0) always gives the same input. --output writes it to
+        FILE instead, with the answers known from how it was built in a
+        file of the same name ending in .answers.
+
+Every command also accepts -v to show debug output from the solvers, or
+--trace to show every step. This goes to stderr, so stdout only carries the
+answers.";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json
+}
+
+struct RunArgs {
+    day: Option<u32>, // None means all days
+    parts: Vec<Part>,
+    input_path: Option<String>,
+    format: Format,
+    params: Params,
+    jobs: usize, // Worker threads for 'all'
+    cross_check: bool,
+    example: bool // Run the embedded examples instead of an input
+}
+
+fn main() {
+    let args = log::take_flags(env::args().skip(1).collect());
+    let result = match args.split_first() {
+        Some((cmd, rest)) if cmd == "run" => parse_run_args(rest).map(|r| run(&r)),
+        Some((cmd, rest)) if cmd == "verify" => verify::parse_verify_args(rest).map(|v| verify::verify(&v)),
+        Some((cmd, rest)) if cmd == "bench" => bench::parse_bench_args(rest).map(|b| bench::bench(&b)),
+        Some((cmd, rest)) if cmd == "fetch" => fetch::parse_fetch_args(rest).map(|f| fetch::fetch(&f)),
+        Some((cmd, rest)) if cmd == "submit" => submit::parse_submit_args(rest).map(|s| submit::submit(&s)),
+        Some((cmd, rest)) if cmd == "new" => new::parse_new_args(rest).map(|n| new::new_day(&n)),
+        Some((cmd, rest)) if cmd == "gen" => gen::parse_gen_args(rest).map(|g| gen::gen(&g)),
+        Some((cmd, _)) => Err(format!("Unknown command '{cmd}'")),
+        None => Err(String::from("Missing command"))
+    };
+    if let Err(e) = result {
+        eprintln!("Error: {e}\n\n{USAGE}");
+        process::exit(2);
+    }
+}
+
+fn run(run_args: &RunArgs) {
+    if run_args.cross_check {
+        check::run_cross_checks(run_args);
+        return;
+    }
+    if run_args.example {
+        examples::run_examples(run_args);
+        return;
+    }
+    match run_args.day {
+        Some(day) => {
+            let input = match input::load(day, run_args.input_path.as_deref()) {
+                Ok(s) => s,
+                Err(e) => {
+                    report_failure(day, run_args, &format!("could not read input: {e}"));
+                    process::exit(1);
+                }
+            };
+            if let Err(e) = run_day(days::get(day), run_args, &input) {
+                report_failure(day, run_args, &format!("invalid input: {e}"));
+                process::exit(1);
+            }
+        },
+        None => run_all::run_all(run_args)
+    }
+}
+
+// Text output reports the failure once on stderr, while JSON output gives each requested part an error record
+fn report_failure(day: u32, run_args: &RunArgs, error: &str) {
+    match run_args.format {
+        Format::Text => eprintln!("Error: {error}"),
+        Format::Json => {
+            for part in &run_args.parts {
+                println!("{}", Record::failed(day, *part, error).to_json());
+            }
+        }
+    }
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut day_arg = None;
+    let mut parts = Part::ALL.to_vec();
+    let mut input_path = None;
+    let mut format = Format::Text;
+    let mut params = Params::new();
+    let mut jobs = thread::available_parallelism().map_or(1, |n| n.get());
+    let mut cross_check = false;
+    let mut example = false;
+
+    let mut arg_iter = args.iter();
+    while let Some(arg) = arg_iter.next() {
+        if arg == "--part" || arg == "-p" {
+            let part_str = arg_iter.next().ok_or("--part requires a value")?;
+            parts = match part_str.as_str() {
+                "1" => vec![Part::One],
+                "2" => vec![Part::Two],
+                _ => return Err(format!("Invalid part '{part_str}', expected 1 or 2"))
+            };
+        }
+        else if arg == "--param" {
+            params.set(arg_iter.next().ok_or("--param requires a value")?)?;
+        }
+        else if arg == "--jobs" || arg == "-j" {
+            let jobs_str = arg_iter.next().ok_or("--jobs requires a value")?;
+            jobs = match jobs_str.parse::<usize>() {
+                Ok(n) if n > 0 => n,
+                _ => return Err(format!("Invalid job count '{jobs_str}'"))
+            };
+        }
+        else if arg == "--format" {
+            let format_str = arg_iter.next().ok_or("--format requires a value")?;
+            format = match format_str.as_str() {
+                "text" => Format::Text,
+                "json" => Format::Json,
+                _ => return Err(format!("Invalid format '{format_str}', expected text or json"))
+            };
+        }
+        else if arg == "--cross-check" {
+            cross_check = true;
+        }
+        else if arg == "--example" {
+            example = true;
+        }
+        else if day_arg.is_none() {
+            day_arg = Some(arg);
+        }
+        else if input_path.is_none() {
+            input_path = Some(arg.clone());
+        }
+        else {
+            return Err(format!("Unexpected argument '{arg}'"));
+        }
+    }
+
+    let day = match day_arg.map(|d| d.as_str()) {
+        None => return Err(String::from("Missing day")),
+        Some("all") => None,
+        Some(d) => match d.parse::<u32>() {
+            Ok(n) if (1..=days::NUM_DAYS).contains(&n) => Some(n),
+            _ => return Err(format!("Invalid day '{d}', expected 1-{} or 'all'", days::NUM_DAYS))
+        }
+    };
+    if day.is_none() && input_path.is_some() {
+        return Err(String::from("An input path cannot be given with 'all'"));
+    }
+    if example && (input_path.is_some() || !params.is_empty() || format != Format::Text || cross_check) {
+        return Err(String::from("--example cannot be given with an input, --param, --format or --cross-check"));
+    }
+    if cross_check {
+        if parts.len() != Part::ALL.len() || !params.is_empty() || format != Format::Text {
+            return Err(String::from("--cross-check cannot be given with --part, --param or --format"));
+        }
+        if let Some(d) = day {
+            if aoc::cross_check::get(d).is_none() {
+                let check_days: Vec<String> = aoc::cross_check::CROSS_CHECKS.iter().map(|c| c.day.to_string()).collect();
+                return Err(format!("No cross-check for day {d}, expected one of {}", check_days.join(", ")));
+            }
+        }
+    }
+    match day {
+        Some(d) => params.check(d, days::get(d).params())?,
+        None if !params.is_empty() => return Err(String::from("Parameters cannot be given with 'all'")),
+        None => ()
+    }
+    return Ok(RunArgs { day: day, parts: parts, input_path: input_path, format: format, params: params, jobs: jobs, cross_check: cross_check, example: example });
+}
+
+// Parses the input once and solves each requested part on it
+// A part that could not be solved, because its answer overflowed or the input has no answer, is reported as failed
+// and the other part still runs, then the process exits with status 1
+fn run_day(solver: &dyn Solver, run_args: &RunArgs, input: &str) -> Result<(), ParseError> {
+    let parsed = solver.parse(input)?;
+    let mut failed = false;
+    for part in &run_args.parts {
+        let start = Instant::now();
+        let answer = solver.solve(&*parsed, *part, &run_args.params);
+        let elapsed = start.elapsed();
+        match (answer, run_args.format) {
+            (Ok(a), Format::Text) => println!("Day {:02} Part {part}: {a}", solver.day()),
+            (Ok(a), Format::Json) => println!("{}", Record::solved(solver.day(), *part, &a.to_string(), elapsed).to_json()),
+            (Err(e), Format::Text) => {
+                eprintln!("Error: part {part}: {e}");
+                failed = true;
+            },
+            (Err(e), Format::Json) => {
+                println!("{}", Record::failed(solver.day(), *part, &e.to_string()).to_json());
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+    return Ok(());
+}
@@ -0,0 +1,56 @@
+use std::{path::PathBuf, process};
+use aoc::scaffold;
+
+pub struct NewArgs {
+    day: u32,
+    title: String,
+    root: PathBuf
+}
+
+pub fn parse_new_args(args: &[String]) -> Result<NewArgs, String> {
+    let mut day_arg = None;
+    let mut title = None;
+    let mut root = PathBuf::from(".");
+
+    let mut arg_iter = args.iter();
+    while let Some(arg) = arg_iter.next() {
+        if arg == "--title" {
+            title = Some(arg_iter.next().ok_or("--title requires a value")?.clone());
+        }
+        else if arg == "--root" {
+            root = PathBuf::from(arg_iter.next().ok_or("--root requires a value")?);
+        }
+        else if day_arg.is_none() {
+            day_arg = Some(arg);
+        }
+        else {
+            return Err(format!("Unexpected argument '{arg}'"));
+        }
+    }
+
+    let day = match day_arg.map(|d| d.as_str()) {
+        None => return Err(String::from("Missing day")),
+        Some(d) => match d.parse::<u32>() {
+            Ok(n) if (1..=25).contains(&n) => n,
+            _ => return Err(format!("Invalid day '{d}', expected 1-25"))
+        }
+    };
+    let title = title.unwrap_or_else(|| format!("Day {day}"));
+    return Ok(NewArgs { day: day, title: title, root: root });
+}
+
+pub fn new_day(args: &NewArgs) {
+    match scaffold::create_day(&args.root, args.day, &args.title) {
+        Ok(paths) => {
+            for path in paths {
+                println!("Wrote {}", path.display());
+            }
+            println!("Paste the example from the puzzle text into day{:02}/fixtures/example.txt and its answers into example.answers,", args.day);
+            println!("then fill in parse, part1 and part2 in day{:02}/src/lib.rs", args.day);
+        },
+        Err(e) => {
+            eprintln!("Error: {e}");
+            process::exit(1);
+        }
+    }
+}
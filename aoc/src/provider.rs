@@ -0,0 +1,139 @@
+use std::{env, fs, io::{self, Write}, path::{Path, PathBuf}, process, time::Duration};
+use common::input;
+
+// The puzzles this repository solves, for looking up inputs by (year, day)
+pub const YEAR: u32 = 2023;
+
+// Where downloaded inputs are kept by default, alongside the hand-placed inputs/dayNN.txt files
+pub const DEFAULT_CACHE_DIR: &str = "inputs/cache";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/mapang02/advent_of_code_2023 by mapang02";
+
+// The session token is only read from the environment, so that it does not end up in shell history
+pub fn session_from_env() -> Option<String> {
+    return env::var("AOC_SESSION").ok().filter(|s| !s.is_empty());
+}
+
+// AOC_BASE_URL points the fetcher and submitter at another server with the same paths
+pub fn base_url_from_env() -> String {
+    return env::var("AOC_BASE_URL").unwrap_or_else(|_| String::from(DEFAULT_BASE_URL));
+}
+
+pub(crate) fn http_agent() -> ureq::Agent {
+    return ureq::AgentBuilder::new().timeout(Duration::from_secs(30)).user_agent(USER_AGENT).build();
+}
+
+// Something that can produce a puzzle input which is not cached yet
+pub trait Fetcher {
+    fn fetch(&self, year: u32, day: u32) -> Result<String, String>;
+}
+
+// Downloads inputs from the puzzle site, or anything serving the same paths, using the session cookie of a logged-in user
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent
+}
+
+impl HttpFetcher {
+    pub fn new(base_url: &str, session: &str) -> HttpFetcher {
+        return HttpFetcher { base_url: String::from(base_url.trim_end_matches('/')), session: String::from(session), agent: http_agent() };
+    }
+
+    pub fn url(&self, year: u32, day: u32) -> String {
+        return format!("{}/{year}/day/{day}/input", self.base_url);
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u32, day: u32) -> Result<String, String> {
+        let url = self.url(year, day);
+        let response = match self.agent.get(&url).set("Cookie", &format!("session={}", self.session)).call() {
+            Ok(r) => r,
+            // The site answers 400 to an expired session and 404 to a puzzle that has not unlocked yet
+            Err(ureq::Error::Status(400, _)) => return Err(format!("{url}: server returned 400, the session token may have expired")),
+            Err(ureq::Error::Status(404, _)) => return Err(format!("{url}: server returned 404, the puzzle may not be unlocked yet")),
+            Err(ureq::Error::Status(code, _)) => return Err(format!("{url}: server returned {code}")),
+            Err(e) => return Err(format!("{url}: {e}"))
+        };
+        return response.into_string().map_err(|e| format!("{url}: {e}"));
+    }
+}
+
+// Inputs already downloaded, kept as dir/YEAR/dayNN.txt exactly as they were received
+// Entries are written once and made read-only, so a cached input is never fetched or changed again
+pub struct InputCache {
+    dir: PathBuf
+}
+
+impl InputCache {
+    pub fn new(dir: &Path) -> InputCache {
+        return InputCache { dir: PathBuf::from(dir) };
+    }
+
+    pub fn path(&self, year: u32, day: u32) -> PathBuf {
+        return self.dir.join(year.to_string()).join(format!("day{day:02}.txt"));
+    }
+
+    // None if the input has not been cached
+    pub fn get(&self, year: u32, day: u32) -> Result<Option<String>, String> {
+        let path = self.path(year, day);
+        return match fs::read_to_string(&path) {
+            Ok(s) => Ok(Some(s)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(format!("{}: {e}", path.display()))
+        };
+    }
+
+    // Fails rather than overwriting an input that is already cached
+    // The input is written to a temporary file in the same directory and renamed into place, so that an interrupted
+    // write never leaves a partial input behind to be read as the cached one
+    pub fn store(&self, year: u32, day: u32, input_str: &str) -> Result<(), String> {
+        let path = self.path(year, day);
+        let temp_path = path.with_extension(format!("txt.{}.tmp", process::id()));
+        let result = (|| {
+            fs::create_dir_all(path.parent().unwrap())?;
+            if path.exists() {
+                return Err(io::Error::new(io::ErrorKind::AlreadyExists, "input is already cached"));
+            }
+            let mut file = fs::OpenOptions::new().write(true).create_new(true).open(&temp_path)?;
+            file.write_all(input_str.as_bytes())?;
+            file.sync_all()?;
+            let mut permissions = file.metadata()?.permissions();
+            permissions.set_readonly(true);
+            file.set_permissions(permissions)?;
+            return fs::rename(&temp_path, &path);
+        })();
+        if result.is_err() {
+            let _ = fs::remove_file(&temp_path);
+        }
+        return result.map_err(|e: io::Error| format!("{}: {e}", path.display()));
+    }
+}
+
+// Resolves (year, day) to input text, from the cache when possible and otherwise from the fetcher, caching what it returns
+// Without a fetcher only cached inputs are available, so nothing touches the network
+pub struct InputProvider {
+    cache: InputCache,
+    fetcher: Option<Box<dyn Fetcher>>
+}
+
+impl InputProvider {
+    pub fn new(cache: InputCache, fetcher: Option<Box<dyn Fetcher>>) -> InputProvider {
+        return InputProvider { cache: cache, fetcher: fetcher };
+    }
+
+    pub fn get(&self, year: u32, day: u32) -> Result<String, String> {
+        if let Some(input_str) = self.cache.get(year, day)? {
+            return Ok(input::normalize(&input_str));
+        }
+        let Some(fetcher) = &self.fetcher else {
+            return Err(format!("{} is not cached and no session token is set", self.cache.path(year, day).display()));
+        };
+        let input_str = fetcher.fetch(year, day)?;
+        self.cache.store(year, day, &input_str)?;
+        return Ok(input::normalize(&input_str));
+    }
+}
@@ -0,0 +1,50 @@
+use std::time::Duration;
+use common::Part;
+
+// The outcome of running one part of one day, as reported by `aoc run --format json`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub day: u32,
+    pub part: Part,
+    pub answer: Option<String>,
+    pub elapsed: Option<Duration>,
+    pub error: Option<String>
+}
+
+impl Record {
+    pub fn solved(day: u32, part: Part, answer: &str, elapsed: Duration) -> Record {
+        return Record { day: day, part: part, answer: Some(String::from(answer)), elapsed: Some(elapsed), error: None };
+    }
+
+    pub fn failed(day: u32, part: Part, error: &str) -> Record {
+        return Record { day: day, part: part, answer: None, elapsed: None, error: Some(String::from(error)) };
+    }
+
+    // A single-line JSON object, so that a run produces one record per line
+    // Answers are always strings, since some do not fit in a double and day answers can be text
+    pub fn to_json(&self) -> String {
+        let answer = self.answer.as_deref().map_or(String::from("null"), json_string);
+        let elapsed_ns = self.elapsed.map_or(String::from("null"), |e| e.as_nanos().to_string());
+        let error = self.error.as_deref().map_or(String::from("null"), json_string);
+        return format!("{{\"day\": {}, \"part\": {}, \"answer\": {answer}, \"elapsed_ns\": {elapsed_ns}, \"error\": {error}}}",
+                       self.day, self.part);
+    }
+}
+
+// Quotes and escapes a string as a JSON string literal
+pub fn json_string(s: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => quoted += "\\\"",
+            '\\' => quoted += "\\\\",
+            '\n' => quoted += "\\n",
+            '\r' => quoted += "\\r",
+            '\t' => quoted += "\\t",
+            c if (c as u32) < 0x20 => quoted += &format!("\\u{:04x}", c as u32),
+            c => quoted.push(c)
+        }
+    }
+    quoted.push('"');
+    return quoted;
+}
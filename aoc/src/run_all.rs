@@ -0,0 +1,166 @@
+use std::{cell::RefCell, cmp, panic::{self, AssertUnwindSafe}, process, sync::{atomic::{AtomicU32, Ordering}, mpsc}, thread, time::{Duration, Instant}};
+use aoc::{days, record::Record};
+use common::{input::InputSource, Part};
+use crate::{Format, RunArgs};
+
+// Outcome of running one day on its default input
+struct DayRun {
+    day: u32,
+    records: Vec<Record>, // One per requested part, including failed ones
+    parse_time: Option<Duration>,
+    status: String,
+    failed: bool // Missing inputs are skipped rather than failed
+}
+
+impl DayRun {
+    fn without_answers(day: u32, parts: &[Part], reason: &str, failed: bool) -> DayRun {
+        let records = parts.iter().map(|part| Record::failed(day, *part, reason)).collect();
+        return DayRun { day: day, records: records, parse_time: None, status: String::from(reason), failed: failed };
+    }
+
+    fn part_time(&self, part: Part) -> Option<Duration> {
+        return self.records.iter().find(|r| r.part == part).and_then(|r| r.elapsed);
+    }
+
+    fn total_time(&self) -> Option<Duration> {
+        return self.parse_time.map(|t| t + self.records.iter().filter_map(|r| r.elapsed).sum());
+    }
+}
+
+thread_local! {
+    // Message and location of the latest panic on this thread, recorded by the panic hook
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+// Replaces the default panic hook's message by recording it, so that catch_panic can report it in the results
+// Undo with panic::take_hook once the panics are no longer caught
+pub fn record_panics() {
+    panic::set_hook(Box::new(|info| {
+        let location = info.location().map(|l| format!(" at {l}")).unwrap_or_default();
+        let message = format!("{}{location}", info.payload_as_str().unwrap_or("unknown panic"));
+        LAST_PANIC.with(|p| *p.borrow_mut() = Some(message));
+    }));
+}
+
+// Runs f, turning a panic into an error carrying the panic message recorded since record_panics
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    return panic::catch_unwind(AssertUnwindSafe(f)).map_err(|_| {
+        return LAST_PANIC.with(|p| p.borrow_mut().take()).unwrap_or_else(|| String::from("unknown panic"));
+    });
+}
+
+fn run_day(day: u32, run_args: &RunArgs) -> DayRun {
+    let parts = &run_args.parts;
+    let solver = days::get(day);
+    let input = match InputSource::from_arg(day, None).read() {
+        Ok(s) => s,
+        Err(e) => return DayRun::without_answers(day, parts, &format!("could not read input: {e}"), false)
+    };
+
+    let start = Instant::now();
+    let parsed = match catch_panic(|| solver.parse(&input)) {
+        Ok(Ok(p)) => p,
+        Ok(Err(e)) => return DayRun::without_answers(day, parts, &format!("invalid input: {e}"), true),
+        Err(msg) => return DayRun::without_answers(day, parts, &format!("panicked while parsing: {msg}"), true)
+    };
+    let parse_time = start.elapsed();
+
+    // A panic in one part still lets the other part run
+    let mut records = Vec::new();
+    let mut problems = Vec::new();
+    for part in parts {
+        let start = Instant::now();
+        match catch_panic(|| solver.solve(&*parsed, *part, &run_args.params)) {
+            Ok(Ok(answer)) => records.push(Record::solved(day, *part, &answer.to_string(), start.elapsed())),
+            Ok(Err(e)) => {
+                problems.push(format!("part {part}: {e}"));
+                records.push(Record::failed(day, *part, &e.to_string()));
+            },
+            Err(msg) => {
+                problems.push(format!("part {part} panicked: {msg}"));
+                records.push(Record::failed(day, *part, &format!("panicked: {msg}")));
+            }
+        }
+    }
+    let status = if problems.is_empty() { String::from("ok") } else { problems.join("; ") };
+    return DayRun { day: day, records: records, parse_time: Some(parse_time), status: status, failed: !problems.is_empty() };
+}
+
+fn format_time(time: Option<Duration>) -> String {
+    return match time {
+        Some(t) => format!("{t:.2?}"),
+        None => String::from("-")
+    };
+}
+
+// Runs every day on its default input on a pool of worker threads, then prints the answers in day order
+// Text output ends with a table of timings, slowest day first
+// Exits with status 1 if any input was invalid or any part panicked or could not be solved
+pub fn run_all(run_args: &RunArgs) {
+    // Panics are reported in the results
+    record_panics();
+
+    let next_day = AtomicU32::new(1);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..run_args.jobs {
+            let sender = sender.clone();
+            let next_day = &next_day;
+            scope.spawn(move || {
+                loop {
+                    let day = next_day.fetch_add(1, Ordering::Relaxed);
+                    if day > days::NUM_DAYS {
+                        break;
+                    }
+                    sender.send(run_day(day, run_args)).unwrap();
+                }
+            });
+        }
+    });
+    drop(sender);
+    let _ = panic::take_hook();
+
+    let mut runs: Vec<DayRun> = receiver.into_iter().collect();
+    runs.sort_by_key(|r| r.day);
+    match run_args.format {
+        Format::Text => {
+            for run in &runs {
+                for record in &run.records {
+                    if let Some(answer) = &record.answer {
+                        println!("Day {:02} Part {}: {answer}", record.day, record.part);
+                    }
+                }
+            }
+            print_summary(&runs);
+        },
+        Format::Json => {
+            for run in &runs {
+                for record in &run.records {
+                    println!("{}", record.to_json());
+                }
+            }
+        }
+    }
+
+    if runs.iter().any(|r| r.failed) {
+        process::exit(1);
+    }
+}
+
+// Slowest days first, with days that did not run at the end
+fn print_summary(runs: &[DayRun]) {
+    let mut sorted_runs: Vec<&DayRun> = runs.iter().collect();
+    sorted_runs.sort_by_key(|r| (r.total_time().is_none(), cmp::Reverse(r.total_time()), r.day));
+
+    println!();
+    println!("Day  {:>10}  {:>10}  {:>10}  {:>10}  Status", "Parse", "Part 1", "Part 2", "Total");
+    for run in sorted_runs {
+        println!("{:02}   {:>10}  {:>10}  {:>10}  {:>10}  {}", run.day, format_time(run.parse_time), format_time(run.part_time(Part::One)),
+                 format_time(run.part_time(Part::Two)), format_time(run.total_time()), run.status);
+    }
+    let total: Duration = runs.iter().filter_map(|r| r.total_time()).sum();
+    let num_ok = runs.iter().filter(|r| r.total_time().is_some() && !r.failed).count();
+    let num_failed = runs.iter().filter(|r| r.failed).count();
+    let num_skipped = runs.len() - num_ok - num_failed;
+    println!("{num_ok} ok, {num_failed} failed, {num_skipped} skipped, {} total solving time", format_time(Some(total)));
+}
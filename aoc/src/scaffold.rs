@@ -0,0 +1,146 @@
+use std::{fs, path::{Path, PathBuf}};
+use crate::provider;
+
+const CARGO_TOML_TEMPLATE: &str = include_str!("../templates/Cargo.toml.in");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.in");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.in");
+const ANSWERS_TEMPLATE: &str = include_str!("../templates/example.answers.in");
+
+// Files of the workspace that list every day, relative to its root, and how to add a day to each
+const REGISTRATIONS: [(&str, fn(&str, u32) -> Result<String, String>); 5] = [
+    ("Cargo.toml", add_workspace_member),
+    ("aoc/Cargo.toml", add_dependency),
+    ("aoc/src/days.rs", add_solver),
+    ("aoc/tests/examples.rs", add_test),
+    ("aoc/tests/line_endings.rs", add_test)
+];
+
+// Fills in a template for the given day
+pub fn render(template: &str, day: u32, title: &str) -> String {
+    return template.replace("__NN__", &format!("{day:02}"))
+                   .replace("__DAY__", &day.to_string())
+                   .replace("__TITLE__", title)
+                   .replace("__YEAR__", &provider::YEAR.to_string());
+}
+
+// Creates dayNN as a new crate in the workspace at root, with stub parse/part1/part2 functions and an empty
+// example fixture, and registers it with the workspace, the aoc runner and the example tests
+// Days are added in order, since the runner looks solvers up by position
+// Returns every file that was created or changed
+pub fn create_day(root: &Path, day: u32, title: &str) -> Result<Vec<PathBuf>, String> {
+    let crate_dir = root.join(format!("day{day:02}"));
+    if crate_dir.exists() {
+        return Err(format!("{} already exists", crate_dir.display()));
+    }
+
+    // Work out every change before writing anything, so that a workspace that does not look as expected is left alone
+    let mut changes = Vec::new();
+    for (relative_path, register) in REGISTRATIONS {
+        let path = root.join(relative_path);
+        let original = fs::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+        let updated = register(&original, day).map_err(|e| format!("{}: {e}", path.display()))?;
+        changes.push((path, updated));
+    }
+    changes.push((crate_dir.join("Cargo.toml"), render(CARGO_TOML_TEMPLATE, day, title)));
+    changes.push((crate_dir.join("src").join("lib.rs"), render(LIB_TEMPLATE, day, title)));
+    changes.push((crate_dir.join("src").join("main.rs"), render(MAIN_TEMPLATE, day, title)));
+    changes.push((crate_dir.join("fixtures").join("example.txt"), String::new()));
+    changes.push((crate_dir.join("fixtures").join("example.answers"), render(ANSWERS_TEMPLATE, day, title)));
+
+    let mut written = Vec::new();
+    for (path, contents) in changes {
+        fs::create_dir_all(path.parent().unwrap()).map_err(|e| format!("{}: {e}", path.display()))?;
+        fs::write(&path, contents).map_err(|e| format!("{}: {e}", path.display()))?;
+        written.push(path);
+    }
+    return Ok(written);
+}
+
+// The text between `open` and the matching `close` that follows it, as a byte range
+fn find_block(text: &str, open: &str, close: &str) -> Result<(usize, usize), String> {
+    let start = text.find(open).ok_or_else(|| format!("could not find '{}'", open.trim()))? + open.len();
+    let end = start + text[start..].find(close).ok_or_else(|| format!("could not find '{}' after '{}'", close.trim(), open.trim()))?;
+    return Ok((start, end));
+}
+
+// Lists day entries five to a line, as in the workspace members and the test macros
+fn day_lines(entries: &[String], indent: &str) -> String {
+    let mut lines = String::new();
+    for chunk in entries.chunks(5) {
+        lines += &format!("{indent}{}\n", chunk.join(" "));
+    }
+    return lines;
+}
+
+// The last day in a list of days must be the one before the new day
+fn check_next(last_day: u32, day: u32) -> Result<(), String> {
+    if day <= last_day {
+        return Err(format!("day {day} is already registered"));
+    }
+    else if day != last_day + 1 {
+        return Err(format!("day {day} cannot be added before day {}", last_day + 1));
+    }
+    return Ok(());
+}
+
+// Adds "dayNN" to the members of the root Cargo.toml
+pub fn add_workspace_member(cargo_toml: &str, day: u32) -> Result<String, String> {
+    let (start, end) = find_block(cargo_toml, "members = [\n", "]")?;
+    let mut other_members = Vec::new();
+    let mut day_members = Vec::new();
+    for member in cargo_toml[start..end].split(',').map(|m| m.trim()).filter(|m| !m.is_empty()) {
+        let name = member.trim_matches('"');
+        match name.strip_prefix("day").and_then(|d| d.parse::<u32>().ok()) {
+            Some(d) => day_members.push((d, format!("{member},"))),
+            None => other_members.push(format!("    {member},\n"))
+        }
+    }
+    check_next(day_members.last().map_or(0, |m| m.0), day)?;
+    day_members.push((day, format!("\"day{day:02}\",")));
+
+    let day_entries: Vec<String> = day_members.into_iter().map(|m| m.1).collect();
+    let members = other_members.concat() + &day_lines(&day_entries, "    ");
+    return Ok(format!("{}{members}{}", &cargo_toml[..start], &cargo_toml[end..]));
+}
+
+// Adds dayNN as a path dependency of the aoc crate, after the other days
+pub fn add_dependency(cargo_toml: &str, day: u32) -> Result<String, String> {
+    let (start, end) = find_block(cargo_toml, "[dependencies]\n", "\n[")?;
+    let mut lines: Vec<String> = cargo_toml[start..end].lines().map(String::from).collect();
+    let last_day = lines.iter().filter_map(|l| l.strip_prefix("day")?.split(' ').next()?.parse::<u32>().ok()).max().unwrap_or(0);
+    check_next(last_day, day)?;
+    let insert_at = lines.iter().rposition(|l| l.starts_with("day") || l.starts_with("common ")).map_or(lines.len(), |i| i + 1);
+    lines.insert(insert_at, format!("day{day:02} = {{ path = \"../day{day:02}\" }}"));
+    return Ok(format!("{}{}\n{}", &cargo_toml[..start], lines.join("\n"), &cargo_toml[end..]));
+}
+
+// Raises NUM_DAYS and appends the day's solver to SOLVERS in aoc/src/days.rs
+pub fn add_solver(days_rs: &str, day: u32) -> Result<String, String> {
+    let (num_start, num_end) = find_block(days_rs, "pub const NUM_DAYS: u32 = ", ";")?;
+    let num_days = days_rs[num_start..num_end].parse::<u32>().map_err(|_| String::from("expected NUM_DAYS to be a number"))?;
+    check_next(num_days, day)?;
+
+    let (start, end) = find_block(days_rs, "= [\n", "];")?;
+    let mut solvers: Vec<String> = days_rs[start..end].split(',').map(|s| String::from(s.trim())).filter(|s| !s.is_empty()).collect();
+    if solvers.len() != num_days as usize {
+        return Err(format!("expected {num_days} solvers, found {}", solvers.len()));
+    }
+    solvers.push(format!("&day{day:02}::Day{day:02}"));
+    let solvers_text = solvers.iter().map(|s| format!("    {s}")).collect::<Vec<String>>().join(",\n") + "\n";
+    return Ok(format!("{}{day}{}{solvers_text}{}", &days_rs[..num_start], &days_rs[num_end..start], &days_rs[end..]));
+}
+
+// Adds `dayNN: N` to the macro invocation that generates one test per day, which ends the file
+pub fn add_test(tests_rs: &str, day: u32) -> Result<String, String> {
+    let start = tests_rs.rfind("!(\n").ok_or("could not find the test macro invocation")? + "!(\n".len();
+    let end = start + tests_rs[start..].find(");").ok_or("could not find the end of the test macro invocation")?;
+    let mut entries: Vec<String> = tests_rs[start..end].split(',').map(|e| String::from(e.trim())).filter(|e| !e.is_empty()).collect();
+    let last_day = entries.last().and_then(|e| e.split(": ").nth(1)).map_or(Ok(0), |d| d.parse::<u32>())
+                          .map_err(|_| String::from("expected entries of the form 'dayNN: N'"))?;
+    check_next(last_day, day)?;
+    entries.push(format!("day{day:02}: {day}"));
+
+    let last = entries.len() - 1;
+    let entries: Vec<String> = entries.into_iter().enumerate().map(|(i, e)| if i < last { e + "," } else { e }).collect();
+    return Ok(format!("{}{}{}", &tests_rs[..start], day_lines(&entries, "    "), &tests_rs[end..]));
+}
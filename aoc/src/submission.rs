@@ -0,0 +1,286 @@
+use std::{fmt, fs, io, path::Path, time::Duration};
+use common::Part;
+use toml::{Table, Value};
+use crate::provider;
+
+// Where the outcome of every submission is kept by default, next to the answers ledger
+pub const DEFAULT_LOG_PATH: &str = "inputs/submissions.toml";
+
+// The site rejects answers sent less than a minute apart, and makes the wait longer after repeated wrong answers
+pub const COOLDOWN: Duration = Duration::from_secs(60);
+
+// What the site said about a submitted answer
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Incorrect, // Wrong, with no hint in which direction
+    TooHigh,
+    TooLow,
+    RateLimited(Option<Duration>), // Not checked, since the last answer was too recent, with the wait left when the page gave it
+    WrongLevel, // Not checked, usually because the part was already solved
+    Unrecognized
+}
+
+impl Outcome {
+    pub fn is_wrong(&self) -> bool {
+        return matches!(self, Outcome::Incorrect | Outcome::TooHigh | Outcome::TooLow);
+    }
+
+    // Stored name, which is also what `aoc submit` prints
+    pub fn name(&self) -> &'static str {
+        return match self {
+            Outcome::Correct => "correct",
+            Outcome::Incorrect => "incorrect",
+            Outcome::TooHigh => "too high",
+            Outcome::TooLow => "too low",
+            Outcome::RateLimited(_) => "rate limited",
+            Outcome::WrongLevel => "wrong level",
+            Outcome::Unrecognized => "unrecognized"
+        };
+    }
+
+    fn from_name(name: &str, wait: Option<Duration>) -> Option<Outcome> {
+        return match name {
+            "correct" => Some(Outcome::Correct),
+            "incorrect" => Some(Outcome::Incorrect),
+            "too high" => Some(Outcome::TooHigh),
+            "too low" => Some(Outcome::TooLow),
+            "rate limited" => Some(Outcome::RateLimited(wait)),
+            "wrong level" => Some(Outcome::WrongLevel),
+            "unrecognized" => Some(Outcome::Unrecognized),
+            _ => None
+        };
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::RateLimited(Some(wait)) => write!(f, "{} ({}s left to wait)", self.name(), wait.as_secs()),
+            _ => write!(f, "{}", self.name())
+        }
+    }
+}
+
+// Reads the outcome from the page returned after submitting, which describes it in prose
+pub fn parse_response(page: &str) -> Outcome {
+    if page.contains("That's the right answer") {
+        return Outcome::Correct;
+    }
+    else if page.contains("That's not the right answer") {
+        if page.contains("your answer is too high") {
+            return Outcome::TooHigh;
+        }
+        else if page.contains("your answer is too low") {
+            return Outcome::TooLow;
+        }
+        return Outcome::Incorrect;
+    }
+    else if page.contains("You gave an answer too recently") {
+        return Outcome::RateLimited(parse_wait(page));
+    }
+    else if page.contains("You don't seem to be solving the right level") {
+        return Outcome::WrongLevel;
+    }
+    return Outcome::Unrecognized;
+}
+
+// The wait is given as "You have 1m 30s left to wait", or just "You have 45s left to wait"
+// None if the page words it any other way, as the wait is then unknown
+fn parse_wait(page: &str) -> Option<Duration> {
+    const UNITS: [(char, u64); 3] = [('h', 3600), ('m', 60), ('s', 1)];
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+    let wait_str = &page[start..end];
+    if wait_str.trim().is_empty() {
+        return None;
+    }
+    let mut secs: u64 = 0;
+    for amount in wait_str.split_whitespace() {
+        let (num_str, unit_secs) = UNITS.iter().find_map(|(unit, unit_secs)| Some((amount.strip_suffix(*unit)?, *unit_secs)))?;
+        let num: u64 = num_str.parse().ok()?;
+        secs = secs.checked_add(num.checked_mul(unit_secs)?)?;
+    }
+    return Some(Duration::from_secs(secs));
+}
+
+// Posts answers to the puzzle site, or anything serving the same paths, as a logged-in user
+pub struct Submitter {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent
+}
+
+impl Submitter {
+    pub fn new(base_url: &str, session: &str) -> Submitter {
+        return Submitter { base_url: String::from(base_url.trim_end_matches('/')), session: String::from(session), agent: provider::http_agent() };
+    }
+
+    pub fn url(&self, year: u32, day: u32) -> String {
+        return format!("{}/{year}/day/{day}/answer", self.base_url);
+    }
+
+    pub fn submit(&self, year: u32, day: u32, part: Part, answer: &str) -> Result<Outcome, String> {
+        let url = self.url(year, day);
+        let level = part.to_string();
+        let request = self.agent.post(&url).set("Cookie", &format!("session={}", self.session));
+        let response = match request.send_form(&[("level", level.as_str()), ("answer", answer)]) {
+            Ok(r) => r,
+            Err(ureq::Error::Status(code, _)) => return Err(format!("{url}: server returned {code}")),
+            Err(e) => return Err(format!("{url}: {e}"))
+        };
+        let page = response.into_string().map_err(|e| format!("{url}: {e}"))?;
+        return Ok(parse_response(&page));
+    }
+}
+
+// One answer that was sent, and what came of it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub time: u64, // Seconds since the Unix epoch
+    pub day: u32,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: Outcome
+}
+
+// Every submission made, stored as TOML with one table per submission, oldest first:
+//
+//     [[submission]]
+//     answer = "54304"
+//     day = 1
+//     outcome = "correct"
+//     part = 1
+//     time = 1701406920
+//
+// Rate limited submissions also store the wait the site asked for, in seconds
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SubmissionLog {
+    submissions: Vec<Submission>
+}
+
+impl SubmissionLog {
+    // A missing file is an empty log, so that the first submission can start one
+    pub fn load(path: &Path) -> Result<SubmissionLog, String> {
+        return match fs::read_to_string(path) {
+            Ok(toml_str) => SubmissionLog::parse(&toml_str).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(SubmissionLog::default()),
+            Err(e) => Err(format!("{}: {e}", path.display()))
+        };
+    }
+
+    pub fn parse(toml_str: &str) -> Result<SubmissionLog, String> {
+        let table: Table = toml_str.parse().map_err(|e: toml::de::Error| e.message().to_string())?;
+        let mut submissions = Vec::new();
+        let entries = match table.get("submission") {
+            Some(Value::Array(entries)) => entries.as_slice(),
+            Some(_) => return Err(String::from("expected 'submission' to be an array of tables")),
+            None => &[]
+        };
+        for entry in entries {
+            let Value::Table(entry) = entry else {
+                return Err(String::from("expected 'submission' to be an array of tables"));
+            };
+            let int_field = |name: &str| entry.get(name).and_then(|v| v.as_integer()).and_then(|n| u64::try_from(n).ok())
+                                                         .ok_or_else(|| format!("expected a submission to have a non-negative integer '{name}'"));
+            let str_field = |name: &str| entry.get(name).and_then(|v| v.as_str())
+                                                         .ok_or_else(|| format!("expected a submission to have a string '{name}'"));
+            let part = match int_field("part")? {
+                1 => Part::One,
+                2 => Part::Two,
+                n => return Err(format!("expected part 1 or 2, found {n}"))
+            };
+            let wait = match entry.get("wait") {
+                Some(_) => Some(Duration::from_secs(int_field("wait")?)),
+                None => None
+            };
+            let outcome_name = str_field("outcome")?;
+            let outcome = Outcome::from_name(outcome_name, wait).ok_or_else(|| format!("unknown outcome '{outcome_name}'"))?;
+            submissions.push(Submission {
+                time: int_field("time")?,
+                day: int_field("day")? as u32,
+                part: part,
+                answer: String::from(str_field("answer")?),
+                outcome: outcome
+            });
+        }
+        return Ok(SubmissionLog { submissions: submissions });
+    }
+
+    pub fn submissions(&self) -> &[Submission] {
+        return &self.submissions;
+    }
+
+    pub fn record(&mut self, submission: Submission) {
+        self.submissions.push(submission);
+    }
+
+    // The answer the site accepted for a part, if any
+    pub fn accepted(&self, day: u32, part: Part) -> Option<&str> {
+        return self.submissions.iter().find(|s| s.day == day && s.part == part && s.outcome == Outcome::Correct).map(|s| s.answer.as_str());
+    }
+
+    // How long to wait at `now` before submitting again, going by the last submission and any wait the site asked for
+    pub fn wait_time(&self, now: u64) -> Option<Duration> {
+        let last = self.submissions.last()?;
+        let wait = match last.outcome {
+            Outcome::RateLimited(Some(wait)) => wait.max(COOLDOWN),
+            _ => COOLDOWN
+        };
+        // A wait that ends past the last second a u64 can count never ends
+        let Some(ready_at) = last.time.checked_add(wait.as_secs()) else {
+            return Some(Duration::MAX);
+        };
+        if now >= ready_at {
+            return None;
+        }
+        return Some(Duration::from_secs(ready_at - now));
+    }
+
+    // Why an answer is already known to be wrong, if it is: it was rejected before,
+    // or it lies beyond an answer the site said was too high or too low
+    pub fn known_wrong(&self, day: u32, part: Part, answer: &str) -> Option<String> {
+        if let Some(accepted) = self.accepted(day, part).filter(|a| *a != answer) {
+            return Some(format!("{accepted} was already accepted"));
+        }
+        let answer_num = answer.parse::<i64>().ok();
+        for s in self.submissions.iter().filter(|s| s.day == day && s.part == part) {
+            if s.outcome.is_wrong() && s.answer == answer {
+                return Some(format!("{answer} was already rejected as {}", s.outcome));
+            }
+            let (Some(new), Ok(old)) = (answer_num, s.answer.parse::<i64>()) else {
+                continue;
+            };
+            if s.outcome == Outcome::TooHigh && new > old {
+                return Some(format!("{answer} is above {old}, which was already rejected as too high"));
+            }
+            if s.outcome == Outcome::TooLow && new < old {
+                return Some(format!("{answer} is below {old}, which was already rejected as too low"));
+            }
+        }
+        return None;
+    }
+
+    pub fn to_toml(&self) -> String {
+        let entries = self.submissions.iter().map(|s| {
+            let mut entry = Table::new();
+            entry.insert(String::from("time"), Value::Integer(s.time as i64));
+            entry.insert(String::from("day"), Value::Integer(s.day as i64));
+            entry.insert(String::from("part"), Value::Integer(if s.part == Part::One { 1 } else { 2 }));
+            entry.insert(String::from("answer"), Value::String(s.answer.clone()));
+            entry.insert(String::from("outcome"), Value::String(String::from(s.outcome.name())));
+            if let Outcome::RateLimited(Some(wait)) = s.outcome {
+                // TOML integers stop at i64::MAX, which is as good as a wait that never ends
+                entry.insert(String::from("wait"), Value::Integer(i64::try_from(wait.as_secs()).unwrap_or(i64::MAX)));
+            }
+            return Value::Table(entry);
+        }).collect();
+        let mut table = Table::new();
+        table.insert(String::from("submission"), Value::Array(entries));
+        return table.to_string();
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        return fs::write(path, self.to_toml());
+    }
+}
@@ -0,0 +1,100 @@
+use std::{path::PathBuf, process, time::{SystemTime, UNIX_EPOCH}};
+use aoc::{days, ledger::{self, Ledger}, provider, submission::{self, Outcome, Submission, SubmissionLog, Submitter}};
+use common::{input, params::Params, Part};
+
+pub struct SubmitArgs {
+    day: u32,
+    part: Part,
+    input_path: Option<String>,
+    log_path: PathBuf,
+    answers_path: PathBuf
+}
+
+pub fn parse_submit_args(args: &[String]) -> Result<SubmitArgs, String> {
+    let mut positional = Vec::new();
+    let mut log_path = PathBuf::from(submission::DEFAULT_LOG_PATH);
+    let mut answers_path = PathBuf::from(ledger::DEFAULT_PATH);
+
+    let mut arg_iter = args.iter();
+    while let Some(arg) = arg_iter.next() {
+        if arg == "--log" {
+            log_path = PathBuf::from(arg_iter.next().ok_or("--log requires a value")?);
+        }
+        else if arg == "--answers" {
+            answers_path = PathBuf::from(arg_iter.next().ok_or("--answers requires a value")?);
+        }
+        else if positional.len() < 3 {
+            positional.push(arg.as_str());
+        }
+        else {
+            return Err(format!("Unexpected argument '{arg}'"));
+        }
+    }
+
+    let day = match positional.first() {
+        None => return Err(String::from("Missing day")),
+        Some(d) => match d.parse::<u32>() {
+            Ok(n) if (1..=days::NUM_DAYS).contains(&n) => n,
+            _ => return Err(format!("Invalid day '{d}', expected 1-{}", days::NUM_DAYS))
+        }
+    };
+    let part = match positional.get(1) {
+        None => return Err(String::from("Missing part")),
+        Some(&"1") => Part::One,
+        Some(&"2") => Part::Two,
+        Some(p) => return Err(format!("Invalid part '{p}', expected 1 or 2"))
+    };
+    let input_path = positional.get(2).map(|p| String::from(*p));
+    return Ok(SubmitArgs { day: day, part: part, input_path: input_path, log_path: log_path, answers_path: answers_path });
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("Error: {message}");
+    process::exit(1);
+}
+
+// Solves the part on the day's input and submits the answer, unless the log shows it would be wasted:
+// the answer was rejected before, or the cooldown since the last submission has not passed
+// Every submission is logged, and accepted answers are also added to the answers ledger for `aoc verify`
+// Exits with status 1 unless the answer was accepted
+pub fn submit(args: &SubmitArgs) {
+    let input_str = input::load(args.day, args.input_path.as_deref()).unwrap_or_else(|e| fail(&format!("could not read input: {e}")));
+    let solver = days::get(args.day);
+    let parsed = solver.parse(&input_str).unwrap_or_else(|e| fail(&format!("invalid input: {e}")));
+    let solved = solver.solve(&*parsed, args.part, &Params::new()).unwrap_or_else(|e| fail(&e.to_string()));
+    let answer = solved.to_string();
+    println!("Day {:02} Part {}: {answer}", args.day, args.part);
+
+    let mut log = SubmissionLog::load(&args.log_path).unwrap_or_else(|e| fail(&format!("could not read submissions: {e}")));
+    if log.accepted(args.day, args.part) == Some(answer.as_str()) {
+        println!("Already accepted");
+        return;
+    }
+    if let Some(reason) = log.known_wrong(args.day, args.part, &answer) {
+        fail(&format!("not submitting, {reason}"));
+    }
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+    if let Some(wait) = log.wait_time(now) {
+        fail(&format!("not submitting, wait {}s after the last submission", wait.as_secs()));
+    }
+    let Some(session) = provider::session_from_env() else {
+        fail("not submitting, no session token is set in AOC_SESSION");
+    };
+
+    let submitter = Submitter::new(&provider::base_url_from_env(), &session);
+    let outcome = submitter.submit(provider::YEAR, args.day, args.part, &answer).unwrap_or_else(|e| fail(&e));
+    println!("Submitted: {outcome}");
+
+    log.record(Submission { time: now, day: args.day, part: args.part, answer: answer.clone(), outcome: outcome });
+    if let Err(e) = log.save(&args.log_path) {
+        fail(&format!("could not write submissions: {}: {e}", args.log_path.display()));
+    }
+    if outcome != Outcome::Correct {
+        process::exit(1);
+    }
+    let mut answers = Ledger::load(&args.answers_path).unwrap_or_else(|e| fail(&format!("could not read answers: {e}")));
+    answers.record(args.day, args.part, solved);
+    if let Err(e) = answers.save(&args.answers_path) {
+        fail(&format!("could not write answers: {}: {e}", args.answers_path.display()));
+    }
+}
@@ -0,0 +1,121 @@
+use std::{panic, path::PathBuf, process};
+use aoc::{days, ledger::{self, Ledger}};
+use common::{input::InputSource, params::Params, Part};
+use crate::run_all::{catch_panic, record_panics};
+
+pub struct VerifyArgs {
+    answers_path: PathBuf,
+    record: bool
+}
+
+pub fn parse_verify_args(args: &[String]) -> Result<VerifyArgs, String> {
+    let mut answers_path = PathBuf::from(ledger::DEFAULT_PATH);
+    let mut record = false;
+
+    let mut arg_iter = args.iter();
+    while let Some(arg) = arg_iter.next() {
+        if arg == "--answers" {
+            answers_path = PathBuf::from(arg_iter.next().ok_or("--answers requires a value")?);
+        }
+        else if arg == "--record" {
+            record = true;
+        }
+        else {
+            return Err(format!("Unexpected argument '{arg}'"));
+        }
+    }
+    return Ok(VerifyArgs { answers_path: answers_path, record: record });
+}
+
+// Runs every day on its default input and compares the answers against the ledger
+// A day that panics is reported as failed and the other days still run
+// Exits with status 1 if any answer changed or could not be solved or any input could not be parsed
+pub fn verify(args: &VerifyArgs) {
+    let mut ledger = match Ledger::load(&args.answers_path) {
+        Ok(l) => l,
+        Err(e) => {
+            eprintln!("Error: could not read answers: {e}");
+            process::exit(1);
+        }
+    };
+
+    let mut num_ok = 0;
+    let mut num_mismatched = 0;
+    let mut num_new = 0;
+    let mut num_missing = 0;
+    let mut num_invalid = 0;
+    let mut num_failed = 0;
+    record_panics();
+    for day in 1..(days::NUM_DAYS + 1) {
+        let solver = days::get(day);
+        let input = match InputSource::from_arg(day, None).read() {
+            Ok(s) => s,
+            Err(e) => {
+                println!("Day {day:02}: missing input ({e})");
+                num_missing += 1;
+                continue;
+            }
+        };
+        let parsed = match catch_panic(|| solver.parse(&input)) {
+            Ok(Ok(p)) => p,
+            Ok(Err(e)) => {
+                println!("Day {day:02}: invalid input: {e}");
+                num_invalid += 1;
+                continue;
+            },
+            Err(msg) => {
+                println!("Day {day:02}: FAILED, panicked while parsing: {msg}");
+                num_failed += 1;
+                continue;
+            }
+        };
+
+        for part in Part::ALL {
+            let answer = match catch_panic(|| solver.solve(&*parsed, part, &Params::new())) {
+                Ok(Ok(a)) => a,
+                Ok(Err(e)) => {
+                    println!("Day {day:02} Part {part}: FAILED, {e}");
+                    num_failed += 1;
+                    continue;
+                },
+                Err(msg) => {
+                    println!("Day {day:02} Part {part}: FAILED, panicked: {msg}");
+                    num_failed += 1;
+                    continue;
+                }
+            };
+            // Answers are compared as text, since the ledger reads integers back as signed whatever they were solved as
+            match ledger.get(day, part) {
+                Some(expected) if expected.to_string() == answer.to_string() => {
+                    println!("Day {day:02} Part {part}: ok ({answer})");
+                    num_ok += 1;
+                },
+                Some(expected) => {
+                    println!("Day {day:02} Part {part}: MISMATCH, expected {expected}, got {answer}");
+                    num_mismatched += 1;
+                },
+                None => {
+                    println!("Day {day:02} Part {part}: new answer {answer}");
+                    num_new += 1;
+                    if args.record {
+                        ledger.record(day, part, answer);
+                    }
+                }
+            }
+        }
+    }
+    let _ = panic::take_hook();
+
+    println!();
+    println!("{num_ok} ok, {num_mismatched} mismatched, {num_new} new, {num_failed} failed, {num_missing} missing inputs, {num_invalid} invalid inputs");
+    if args.record && num_new > 0 {
+        if let Err(e) = ledger.save(&args.answers_path) {
+            eprintln!("Error: could not write answers: {}: {e}", args.answers_path.display());
+            process::exit(1);
+        }
+        println!("Recorded {num_new} new answers in {}", args.answers_path.display());
+    }
+    if num_mismatched > 0 || num_failed > 0 || num_invalid > 0 {
+        process::exit(1);
+    }
+}
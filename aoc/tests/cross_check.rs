@@ -0,0 +1,53 @@
+use aoc::{cross_check, generate};
+use common::input;
+
+mod support;
+
+// Runs a day's cross-check on each of its fixtures, returning how many comparisons were made and skipped
+fn check_fixtures(day: u32) -> (usize, usize) {
+    let check = cross_check::get(day).unwrap();
+    let (mut num_compared, mut num_skipped) = (0, 0);
+    for path in support::fixtures(day) {
+        let input_str = input::load(day, path.to_str()).unwrap();
+        let report = (check.check)(&input_str).unwrap();
+        assert_eq!(report.disagreements(), Vec::<&cross_check::Comparison>::new(), "{}", path.display());
+        num_compared += report.comparisons.len();
+        num_skipped += report.skipped.len();
+    }
+    return (num_compared, num_skipped);
+}
+
+#[test]
+fn day05_examples_agree() {
+    // The example's seed ranges are small enough to map seed by seed
+    assert_eq!(check_fixtures(5), (2, 0));
+}
+
+#[test]
+fn day12_examples_agree() {
+    let (num_compared, num_skipped) = check_fixtures(12);
+    assert_eq!(num_compared, 10);
+    assert_eq!(num_skipped, 1);
+}
+
+#[test]
+fn day18_examples_agree() {
+    // The plan hidden in the example's colors is far too large to flood fill
+    assert_eq!(check_fixtures(18), (1, 1));
+}
+
+#[test]
+fn day12_agrees_on_generated_records() {
+    let generated = generate::generate(12, 300, 7).unwrap();
+    let report = (cross_check::get(12).unwrap().check)(&generated.input).unwrap();
+    assert!(report.comparisons.len() > 300);
+    assert!(report.disagreements().is_empty(), "{:?}", report.disagreements());
+}
+
+#[test]
+fn only_some_days_have_cross_checks() {
+    let days: Vec<u32> = cross_check::CROSS_CHECKS.iter().map(|c| c.day).collect();
+    assert_eq!(days, vec![5, 12, 18]);
+    assert!(cross_check::get(1).is_none());
+    assert!((cross_check::get(5).unwrap().check)("seeds: x\n").is_err());
+}
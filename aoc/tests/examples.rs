@@ -0,0 +1,48 @@
+// Every fixture with an answers file must still produce those answers, starting with the worked
+// examples from each day's puzzle text
+
+mod support;
+
+fn check_day(day: u32) {
+    for fixture in support::fixtures(day) {
+        let params = support::fixture_params(&fixture);
+        for (part, expected) in support::expected_answers(&fixture) {
+            let answer = &support::solve_file(day, &fixture, &[part], &params)[0];
+            assert_eq!(answer.to_string(), expected, "day {day:02} part {part}, {}", fixture.display());
+        }
+    }
+}
+
+// `aoc run --example` only knows the examples a day embeds, so each day must embed every example fixture
+// Other fixtures, such as local regression cases, are only used by the tests
+#[test]
+fn every_example_is_embedded() {
+    for day in 1..(aoc::days::NUM_DAYS + 1) {
+        let num_fixtures = support::fixtures(day).iter().filter(|f| f.file_stem().unwrap().to_str().unwrap().starts_with("example")).count();
+        let examples = aoc::days::get(day).examples();
+        assert_eq!(examples.len(), num_fixtures, "day {day:02}");
+        for example in examples {
+            let fixture = support::fixture_dir(day).join(format!("{}.txt", example.name));
+            assert_eq!(*example, support::fixture_example(&fixture), "day {day:02}, {}", example.name);
+        }
+    }
+}
+
+macro_rules! example_tests {
+    ($($name:ident: $day:expr),*) => {
+        $(
+            #[test]
+            fn $name() {
+                check_day($day);
+            }
+        )*
+    };
+}
+
+example_tests!(
+    day01: 1, day02: 2, day03: 3, day04: 4, day05: 5,
+    day06: 6, day07: 7, day08: 8, day09: 9, day10: 10,
+    day11: 11, day12: 12, day13: 13, day14: 14, day15: 15,
+    day16: 16, day17: 17, day18: 18, day19: 19, day20: 20,
+    day21: 21, day22: 22, day23: 23, day24: 24, day25: 25
+);
@@ -0,0 +1,64 @@
+use aoc::{days, generate::{self, Generated}};
+use common::{params::Params, Part};
+
+// Solves a generated input and checks the answers it is known to have
+fn check_known_answers(day: u32, generated: &Generated) {
+    let solver = days::get(day);
+    let parsed = solver.parse(&generated.input).unwrap_or_else(|e| panic!("day {day:02}: {e}\n{}", generated.input));
+    for (part, expected) in &generated.answers {
+        let answer = solver.solve(&*parsed, *part, &Params::new()).unwrap_or_else(|e| panic!("{e}\n{}", generated.input));
+        assert_eq!(&answer.to_string(), expected, "day {day:02} part {part}\n{}", generated.input);
+    }
+}
+
+#[test]
+fn same_seed_gives_same_input() {
+    for generator in &generate::GENERATORS {
+        let first = generate::generate(generator.day, 5, 42).unwrap();
+        assert_eq!(generate::generate(generator.day, 5, 42).unwrap(), first, "day {:02}", generator.day);
+        assert_ne!(generate::generate(generator.day, 5, 43).unwrap().input, first.input, "day {:02}", generator.day);
+    }
+    assert_eq!(generate::generate(1, 5, 42), None);
+}
+
+#[test]
+fn day10_loops_enclose_the_tree() {
+    for seed in 0..20 {
+        for size in [1, 2, 5, 12] {
+            let generated = generate::generate(10, size, seed).unwrap();
+            assert_eq!(generated.answers.len(), 2);
+            check_known_answers(10, &generated);
+        }
+    }
+}
+
+#[test]
+fn day12_records_have_arrangements() {
+    let generated = generate::generate(12, 200, 1).unwrap();
+    for (conditions, groups) in day12::parse(&generated.input).unwrap() {
+        assert!(day12::count_arrangements(&conditions, &groups).unwrap() >= 1, "{conditions:?} {groups:?}");
+    }
+}
+
+#[test]
+fn day20_counters_cycle_with_their_periods() {
+    for seed in 0..5 {
+        for size in [1, 2, 4] {
+            let generated = generate::generate(20, size, seed).unwrap();
+            assert_eq!(generated.answers[0].0, Part::Two);
+            check_known_answers(20, &generated);
+        }
+    }
+}
+
+// Days without known answers still have to produce inputs their solvers accept
+#[test]
+fn day22_and_day23_inputs_solve() {
+    for (day, size) in [(22, 50), (23, 3)] {
+        let solver = days::get(day);
+        let parsed = solver.parse(&generate::generate(day, size, 3).unwrap().input).unwrap();
+        for part in Part::ALL {
+            solver.solve(&*parsed, part, &Params::new()).unwrap();
+        }
+    }
+}
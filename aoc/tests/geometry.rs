@@ -0,0 +1,48 @@
+// The directions and points shared by the days that move around a map
+
+use common::{grid, Direction, Point};
+
+#[test]
+fn turns() {
+    assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    for dir in Direction::ALL {
+        assert_eq!(dir.turn_left().turn_right(), dir);
+        assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+        assert_eq!(dir.reverse().is_vertical(), dir.is_vertical());
+        assert_eq!(Direction::ALL[dir.index()], dir);
+    }
+}
+
+#[test]
+fn offsets_match_grid_neighbors() {
+    let offsets: Vec<(isize, isize)> = Direction::ALL.iter().map(|d| d.offset()).collect();
+    assert_eq!(offsets, grid::NEIGHBORS4);
+    assert_eq!(Direction::Left.unit(), Point::new(0, -1));
+}
+
+#[test]
+fn reads_encodings() {
+    assert_eq!(Direction::from_letter('N'), Some(Direction::Up));
+    assert_eq!(Direction::from_letter('W'), Some(Direction::Left));
+    assert_eq!(Direction::from_letter('X'), None);
+    let digits: Vec<Option<Direction>> = "01234".chars().map(Direction::from_digit).collect();
+    assert_eq!(digits, [Some(Direction::Right), Some(Direction::Down), Some(Direction::Left), Some(Direction::Up), None]);
+    for dir in Direction::ALL {
+        assert_eq!(Direction::from_letter(dir.letter()), Some(dir));
+        assert_eq!(Direction::from_digit(dir.digit()), Some(dir));
+        assert_eq!(dir.to_string(), dir.letter().to_string());
+    }
+}
+
+#[test]
+fn point_arithmetic() {
+    let mut point = Point::ORIGIN;
+    point += Direction::Down.unit() * 3;
+    point -= Direction::Right.unit();
+    assert_eq!(point, Point::new(3, -1));
+    assert_eq!((point - Point::new(1, 1)).manhattan(), 4);
+    assert_eq!(-point + point, Point::ORIGIN);
+    assert_eq!(point.to_grid(), None);
+    assert_eq!(Point::from((2, 5)).to_grid(), Some((2, 5)));
+}
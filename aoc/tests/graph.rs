@@ -0,0 +1,86 @@
+// The graph crate shared by the days that search or cut networks
+
+use graph::{Graph, NodeId};
+
+// Two triangles joined by a single edge, c to d
+fn two_triangles() -> Graph<&'static str, u32> {
+    let mut graph = Graph::undirected();
+    for (from, to) in [("a", "b"), ("b", "c"), ("c", "a"), ("c", "d"), ("d", "e"), ("e", "f"), ("f", "d")] {
+        let (from, to) = (graph.add_node(from), graph.add_node(to));
+        graph.add_edge(from, to, 1);
+    }
+    return graph;
+}
+
+#[test]
+fn interns_nodes() {
+    let mut graph = two_triangles();
+    assert_eq!(graph.num_nodes(), 6);
+    assert_eq!(graph.add_node("c"), NodeId(2));
+    assert_eq!(graph.id(&"d"), Some(NodeId(3)));
+    assert_eq!(graph.id(&"z"), None);
+    assert_eq!(*graph.label(NodeId(4)), "e");
+    assert_eq!(graph.neighbors(NodeId(2)).collect::<Vec<_>>(), [(NodeId(1), 1), (NodeId(0), 1), (NodeId(3), 1)]);
+}
+
+#[test]
+fn finds_components() {
+    let mut graph: Graph<u32, ()> = Graph::directed();
+    let ids: Vec<NodeId> = (0..5).map(|n| graph.add_node(n)).collect();
+    graph.add_edge(ids[1], ids[0], ());
+    graph.add_edge(ids[2], ids[4], ());
+    graph.add_node(5);
+    // Directed edges still join their nodes, but can only be followed forwards
+    assert_eq!(graph.components(), [vec![ids[0], ids[1]], vec![ids[2], ids[4]], vec![ids[3]], vec![NodeId(5)]]);
+    assert_eq!(graph.neighbors(ids[0]).count(), 0);
+    assert_eq!(graph.neighbors(ids[1]).collect::<Vec<_>>(), [(ids[0], ())]);
+}
+
+#[test]
+fn cuts_the_bridge() {
+    let graph = two_triangles();
+    let (a, f) = (graph.id(&"a").unwrap(), graph.id(&"f").unwrap());
+    for flow in [graph::edmonds_karp(&graph, a, f), graph::dinic(&graph, a, f)] {
+        assert_eq!(flow.value(), 1);
+        let cut = flow.min_cut();
+        let mut source_side: Vec<&str> = cut.source_side.iter().map(|n| *graph.label(*n)).collect();
+        source_side.sort();
+        assert_eq!(source_side, ["a", "b", "c"]);
+        assert_eq!(cut.cut_edges, [3]);
+    }
+}
+
+#[test]
+fn flows_respect_direction_and_capacity() {
+    // Two routes from s to t with capacities 3 and 2, and a cross edge that can only help one way
+    let mut graph = Graph::directed();
+    let [s, a, b, t] = ["s", "a", "b", "t"].map(|n| graph.add_node(n));
+    for (from, to, capacity) in [(s, a, 3), (s, b, 2), (a, t, 2), (b, t, 3), (a, b, 1), (t, s, 10)] {
+        graph.add_edge(from, to, capacity);
+    }
+    let edmonds_karp = graph::edmonds_karp(&graph, s, t);
+    let dinic = graph::dinic(&graph, s, t);
+    assert_eq!((edmonds_karp.value(), dinic.value()), (5, 5));
+    for flow in [edmonds_karp, dinic] {
+        let mut flows = flow.flows();
+        flows.sort();
+        assert_eq!(flows, [(s, a, 3), (s, b, 2), (a, b, 1), (a, t, 2), (b, t, 3)]);
+        assert_eq!(flow.min_cut().cut_edges, [0, 1]);
+    }
+    assert_eq!(graph::edmonds_karp(&graph, t, s).value(), 10);
+    assert_eq!(graph::dinic(&graph, s, s).value(), 0);
+}
+
+#[test]
+fn writes_dot() {
+    let mut graph = Graph::directed();
+    let (a, b) = (graph.add_node("a"), graph.add_node("b \"q\""));
+    graph.add_edge(a, b, 2);
+    graph.add_node("c");
+    assert_eq!(graph.to_dot(), "digraph {\n    \"a\";\n    \"b \\\"q\\\"\";\n    \"c\";\n    \"a\" -> \"b \\\"q\\\"\" [label=\"2\"];\n}\n");
+    let mut undirected: Graph<&str, u32> = Graph::undirected();
+    let (x, y) = (undirected.add_node("x"), undirected.add_node("y"));
+    undirected.add_edge(x, y, 1);
+    assert!(undirected.to_dot().starts_with("graph {\n"));
+    assert!(undirected.to_dot().contains("\"x\" -- \"y\" [label=\"1\"];"));
+}
@@ -0,0 +1,48 @@
+// The grid shared by the days that read a map of characters
+
+use common::{parse, Grid};
+
+fn small_grid() -> Grid<char> {
+    return parse::char_grid(1, "abc\ndef\n", "abcdef").unwrap();
+}
+
+#[test]
+fn parses_and_prints() {
+    let grid = small_grid();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(1, 0)], 'd');
+    assert_eq!(grid.get((2, 0)), None);
+    assert_eq!(grid.to_string(), "abc\ndef\n");
+    assert_eq!(grid.row(1), ['d', 'e', 'f']);
+    assert_eq!(grid.column(2).collect::<String>(), "cf");
+    assert_eq!(grid.position(|c| *c == 'e'), Some((1, 1)));
+}
+
+#[test]
+fn maps_cells() {
+    let digits = parse::grid(1, "12\n34\n", "a digit", |c| c.to_digit(10)).unwrap();
+    assert_eq!(digits, Grid::from_rows(vec![vec![1, 2], vec![3, 4]]));
+    assert_eq!(digits.map(|d| d % 2 == 0), Grid::from_fn(2, 2, |(_, col)| col == 1));
+    let error = parse::grid(1, "12\n3x\n", "a digit", |c| c.to_digit(10)).unwrap_err();
+    assert!(error.to_string().contains("a digit"), "{error}");
+}
+
+#[test]
+fn neighbors_stay_in_bounds() {
+    let grid = small_grid();
+    assert_eq!(grid.neighbors4((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+    assert_eq!(grid.neighbors4((1, 1)).collect::<Vec<_>>(), [(0, 1), (1, 0), (1, 2)]);
+    assert_eq!(grid.neighbors8((0, 2)).collect::<Vec<_>>(), [(0, 1), (1, 1), (1, 2)]);
+    assert_eq!(grid.step((1, 2), (0, 1)), None);
+    assert_eq!(grid.step((1, 2), (-1, -2)), Some((0, 0)));
+}
+
+#[test]
+fn transforms() {
+    let grid = small_grid();
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+    assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+    assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+    assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    assert_eq!(grid.transpose().transpose(), grid);
+}
@@ -0,0 +1,58 @@
+// The interval sets shared by the days that map and split ranges of numbers
+
+use common::IntervalSet;
+
+fn ranges(set: &IntervalSet) -> Vec<(i64, i64)> {
+    return set.ranges().map(|r| (r.start, r.end)).collect();
+}
+
+#[test]
+fn builds_from_half_open_and_closed_ranges() {
+    assert_eq!(IntervalSet::from(1..=4000), IntervalSet::from(1..4001));
+    assert_eq!(IntervalSet::from(1..=4000).len(), 4000);
+    assert!(IntervalSet::from(5..5).is_empty());
+    assert_eq!(IntervalSet::from(5..=5).len(), 1);
+    assert_eq!(IntervalSet::new(), IntervalSet::default());
+
+    // Overlapping and touching ranges merge, and empty ones are dropped
+    let set: IntervalSet = vec![10..20, 0..5, 15..25, 5..7, 30..30].into_iter().collect();
+    assert_eq!(ranges(&set), [(0, 7), (10, 25)]);
+    assert_eq!(set.len(), 22);
+    assert_eq!((set.min(), set.max()), (Some(0), Some(24)));
+    assert!(set.contains(6) && !set.contains(7) && set.contains(10) && !set.contains(25));
+    assert_eq!((IntervalSet::new().min(), IntervalSet::new().max()), (None, None));
+}
+
+#[test]
+fn combines_sets() {
+    let a: IntervalSet = vec![0..10, 20..30].into_iter().collect();
+    let b: IntervalSet = vec![5..25, 28..40].into_iter().collect();
+    assert_eq!(ranges(&a.union(&b)), [(0, 40)]);
+    assert_eq!(ranges(&a.intersection(&b)), [(5, 10), (20, 25), (28, 30)]);
+    assert_eq!(ranges(&a.difference(&b)), [(0, 5), (25, 28)]);
+    assert_eq!(ranges(&b.difference(&a)), [(10, 20), (30, 40)]);
+    assert!(a.difference(&a).is_empty());
+    assert_eq!(a.intersection(&IntervalSet::new()), IntervalSet::new());
+    assert_eq!(a.len() + b.len(), a.union(&b).len() + a.intersection(&b).len());
+}
+
+#[test]
+fn splits_and_shifts() {
+    let set: IntervalSet = vec![0..10, 20..30].into_iter().collect();
+    let (below, above) = set.split_at(25);
+    assert_eq!((ranges(&below), ranges(&above)), (vec![(0, 10), (20, 25)], vec![(25, 30)]));
+    let (below, above) = set.split_at(15);
+    assert_eq!((ranges(&below), ranges(&above)), (vec![(0, 10)], vec![(20, 30)]));
+    assert_eq!(set.split_at(-1).0, IntervalSet::new());
+    assert_eq!(ranges(&set.checked_shift(-5).unwrap()), [(-5, 5), (15, 25)]);
+    assert_eq!(set.checked_shift(100).unwrap().len(), set.len());
+    assert_eq!(set.checked_shift(i64::MAX - 30).unwrap().max(), Some(i64::MAX - 1));
+    assert_eq!(set.checked_shift(i64::MAX - 29), None);
+}
+
+#[test]
+fn large_ranges_count_without_overflow() {
+    let everything = IntervalSet::from(i64::MIN..i64::MAX);
+    assert_eq!(everything.len(), u64::MAX);
+    assert_eq!(everything.difference(&IntervalSet::from(0..=9)).len(), u64::MAX - 10);
+}
@@ -0,0 +1,107 @@
+// Inputs that have the right shape but leave a part with nothing to work on must be rejected by the parser, pointing
+// at what is wrong, or by the part that cannot answer them, rather than panic once solving starts
+
+use common::{ParseError, SolveError};
+
+fn error_text<T>(result: Result<T, ParseError>) -> String {
+    return match result {
+        Ok(_) => panic!("input was accepted"),
+        Err(error) => error.to_string()
+    };
+}
+
+#[test]
+fn day18_rejects_bad_colors_and_empty_plans() {
+    let error = error_text(day18::parse("R 6 (#70c710)\nD 5 (#0dc579)\n"));
+    assert!(error.contains("line 2, column 12") && error.contains("'0123'"), "{error}");
+    let error = error_text(day18::parse("R 6 (#70c7g0)\n"));
+    assert!(error.contains("a hexadecimal digit"), "{error}");
+    assert!(error_text(day18::parse("")).contains("found nothing"));
+    let error = error_text(day18::parse("R 0 (#000000)\n"));
+    assert!(error.contains("line 1, column 3") && error.contains("at least 1 meter"), "{error}");
+}
+
+#[test]
+fn day24_needs_enough_hailstones_for_part2() {
+    let hailstones = "19, 13, 30 @ -2, 1, -2\n18, 19, 22 @ -1, -1, -2\n20, 25, 34 @ -2, -2, -4\n12, 31, 28 @ -1, -2, -1\n";
+    let error = error_text(day24::parse(hailstones));
+    assert!(error.contains("line 5") && error.contains("at least 5 hailstones"), "{error}");
+    assert!(error_text(day24::parse("")).contains("found nothing"));
+    assert!(day24::parse(&format!("{hailstones}20, 19, 15 @ 1, -5, -3\n")).is_ok());
+}
+
+#[test]
+fn day08_rejects_undefined_nodes() {
+    let error = error_text(day08::parse("RL\n\nAAA = (BBB, CCC)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n"));
+    assert!(error.contains("line 3, column 13") && error.contains("a node from the list"), "{error}");
+
+    // Without AAA, part 1 has nowhere to start but part 2 can still follow the nodes ending in A
+    let network = day08::parse("L\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)\n").unwrap();
+    assert_eq!(day08::part1(&network), Err(SolveError::unsolvable(8, "there is no node AAA to start from")));
+    assert_eq!(day08::part2(&network), Ok(1));
+}
+
+#[test]
+fn day01_rejects_lines_without_digits() {
+    let error = error_text(day01::parse("two1nine\nabc\n"));
+    assert!(error.contains("line 2, column 1") && error.contains("a digit or a digit spelled out"), "{error}");
+    assert!(day01::parse("eightwothree\n").is_ok());
+}
+
+#[test]
+fn day23_fails_without_a_downhill_route() {
+    let walled = day23::parse("#.#\n###\n#.#\n").unwrap();
+    assert_eq!(day23::part1(&walled), Err(SolveError::unsolvable(23, "the end cannot be reached from the start")));
+    let ring = day23::parse("...\n.#.\n...\n").unwrap();
+    assert_eq!(day23::part1(&ring), Err(SolveError::unsolvable(23, "the slopes let the trails loop back on themselves")));
+}
+
+#[test]
+fn day22_rejects_bricks_in_the_ground() {
+    let error = error_text(day22::parse("1,0,1~1,2,1\n0,0,0~0,0,0\n"));
+    assert!(error.contains("line 2, column 5") && error.contains("a height z of at least 1"), "{error}");
+    let error = error_text(day22::parse("0,0,1~0,0,0\n"));
+    assert!(error.contains("line 1, column 11"), "{error}");
+}
+
+#[test]
+fn day20_fails_without_a_path_to_rx() {
+    let network = day20::parse("broadcaster -> a\n%a -> b\n&b -> a\n").unwrap();
+    assert_eq!(day20::part2(&network), Err(SolveError::unsolvable(20, "no module sends to rx")));
+    let network = day20::parse("broadcaster -> a, b\n%a -> c\n%b -> b\n&c -> rx\n").unwrap();
+    assert_eq!(day20::part2(&network), Err(SolveError::unsolvable(20, "no path from b to c")));
+}
+
+#[test]
+fn day17_fails_when_no_run_ends_at_the_goal() {
+    // Ultra crucible runs of 4 or more blocks do not fit in a 2 by 2 city
+    let city = day17::parse("11\n11\n").unwrap();
+    assert_eq!(day17::part1(&city), Ok(2));
+    assert_eq!(day17::part2(&city), Err(SolveError::unsolvable(17, "no run of the crucible ends on the bottom-right block")));
+}
+
+#[test]
+fn day05_fails_without_seeds() {
+    let almanac = day05::parse("seeds:\n\nseed-to-soil map:\n50 98 2\n").unwrap();
+    assert_eq!(day05::part1(&almanac), Err(SolveError::unsolvable(5, "there are no seeds to plant")));
+    assert_eq!(day05::part2(&almanac), Err(SolveError::unsolvable(5, "the seed ranges hold no seeds")));
+}
+
+#[test]
+fn day08_fails_when_the_path_never_ends() {
+    let network = day08::parse("L\n\nAAA = (BBB, BBB)\nBBB = (BBB, BBB)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+    assert_eq!(day08::part1(&network), Err(SolveError::unsolvable(8, "the path from AAA loops without reaching ZZZ")));
+    assert_eq!(day08::part2(&network), Err(SolveError::unsolvable(8, "the path from AAA loops without reaching a node ending in Z")));
+}
+
+#[test]
+fn day19_fails_when_a_workflow_loops() {
+    let system = day19::parse("in{x<5:in,A}\n\n{x=1,m=2,a=3,s=4}\n{x=5,m=2,a=3,s=4}\n").unwrap();
+    assert_eq!(day19::part1(&system), Err(SolveError::unsolvable(19, "part {x=1,m=2,a=3,s=4} comes back to workflow in")));
+    assert_eq!(day19::part2(&system), Err(SolveError::unsolvable(19, "some ratings come back to workflow in")));
+
+    // Two rules of a workflow sending ratings on to the same workflow is not a loop
+    let system = day19::parse("in{x<5:a,a}\na{x>4:R,A}\n\n{x=1,m=2,a=3,s=4}\n").unwrap();
+    assert_eq!(day19::part1(&system), Ok(10));
+    assert_eq!(day19::part2(&system), Ok(4 * 4000 * 4000 * 4000));
+}
@@ -0,0 +1,43 @@
+use aoc::ledger::Ledger;
+use common::{Answer, Part};
+
+#[test]
+fn reads_integer_and_string_answers() {
+    let ledger = Ledger::parse("[day01]\npart1 = 54304\npart2 = \"54418\"\n\n[day25]\npart1 = 543256\n").unwrap();
+    assert_eq!(ledger.get(1, Part::One), Some(&Answer::Int(54304)));
+    assert_eq!(ledger.get(1, Part::Two), Some(&Answer::Text(String::from("54418"))));
+    assert_eq!(ledger.get(25, Part::One), Some(&Answer::Int(543256)));
+    assert_eq!(ledger.get(25, Part::Two), None);
+    assert_eq!(ledger.get(2, Part::One), None);
+}
+
+#[test]
+fn round_trips_through_toml() {
+    let mut ledger = Ledger::default();
+    ledger.record(9, Part::Two, Answer::Int(2));
+    ledger.record(9, Part::One, Answer::Int(114));
+    ledger.record(3, Part::One, Answer::Text(String::from("not a number")));
+    ledger.record(3, Part::Two, Answer::Text(String::from("007")));
+    let toml_str = ledger.to_toml();
+    assert_eq!(toml_str, "[day03]\npart1 = \"not a number\"\npart2 = \"007\"\n\n[day09]\npart1 = 114\npart2 = 2\n");
+    assert_eq!(Ledger::parse(&toml_str).unwrap(), ledger);
+}
+
+// Unsigned and wide answers are read back as signed integers, or as text when they do not fit
+#[test]
+fn writes_integers_too_large_for_toml_as_strings() {
+    let mut ledger = Ledger::default();
+    ledger.record(1, Part::One, Answer::UInt(u64::MAX));
+    ledger.record(1, Part::Two, Answer::Wide(-5));
+    let parsed = Ledger::parse(&ledger.to_toml()).unwrap();
+    assert_eq!(parsed.get(1, Part::One), Some(&Answer::Text(u64::MAX.to_string())));
+    assert_eq!(parsed.get(1, Part::Two), Some(&Answer::Int(-5)));
+}
+
+#[test]
+fn rejects_unknown_keys() {
+    assert!(Ledger::parse("[dayone]\npart1 = 1\n").is_err());
+    assert!(Ledger::parse("[day01]\npart3 = 1\n").is_err());
+    assert!(Ledger::parse("[day01]\npart1 = 1.5\n").is_err());
+    assert!(Ledger::parse("day01 = 1\n").is_err());
+}
@@ -0,0 +1,42 @@
+// The helpers that the day crates expose for reuse, beyond their parse and part functions
+
+use std::collections::HashSet;
+
+#[test]
+fn day12_count_arrangements() {
+    let count = |conditions: &str, groups: &[usize]| day12::count_arrangements(&conditions.chars().collect(), &groups.to_vec()).unwrap();
+    assert_eq!(count("???.###", &[1, 1, 3]), 1);
+    assert_eq!(count(".??..??...?##.", &[1, 1, 3]), 4);
+    assert_eq!(count("?###????????", &[3, 2, 1]), 10);
+    assert_eq!(count("#.#", &[1]), 0);
+}
+
+#[test]
+fn day20_iterate_subgraph() {
+    let network = day20::parse("broadcaster -> a, b, c\n%a -> b\n%b -> c\n%c -> inv\n&inv -> a\n").unwrap();
+    let mut state_map = network.state_map.clone();
+    let mut conj_mem_map = network.conj_mem_map.clone();
+    let counts = day20::iterate_subgraph(&network.connections_map, &mut state_map, &mut conj_mem_map, &HashSet::new());
+    assert_eq!(counts, (8, 4));
+    // The example returns to its starting state after every press
+    assert_eq!(state_map, network.state_map);
+}
+
+#[test]
+fn day22_simulate_drop_unified() {
+    let bricks = day22::parse("1,0,1~1,2,1\n0,0,2~2,0,2\n0,2,3~2,2,3\n0,0,4~0,2,4\n2,0,5~2,2,5\n0,1,6~2,1,6\n1,1,8~1,1,9\n").unwrap();
+    let (settled, num_dropped) = day22::simulate_drop_unified(&bricks);
+    assert_eq!(num_dropped, 5);
+    assert_eq!(settled[&6], ((1, 1, 5), (1, 1, 6)));
+    // Settled bricks stay put
+    assert_eq!(day22::simulate_drop_unified(&settled), (settled.clone(), 0));
+}
+
+#[test]
+fn day25_max_flow() {
+    let graph = day25::parse("a: b c\nc: b\nb: d\n").unwrap();
+    let [a, b, d] = ["a", "b", "d"].map(|n| graph.id(&String::from(n)).unwrap());
+    assert_eq!(graph::edmonds_karp(&graph, a, b).flows().len(), 3);
+    let flow = graph::edmonds_karp(&graph, a, d);
+    assert_eq!(flow.flows().iter().filter(|(_, dest, _)| *dest == d).count(), 1);
+}
@@ -0,0 +1,55 @@
+// Every day must give the same answers whether its input uses LF or CRLF line endings,
+// with or without a trailing newline or trailing blank lines
+
+use std::{env, fs, process};
+
+mod support;
+
+fn check_day(day: u32) {
+    let tmp_dir = env::temp_dir().join(format!("aoc-line-endings-{}-day{day:02}", process::id()));
+    fs::create_dir_all(&tmp_dir).unwrap();
+
+    for fixture in support::fixtures(day) {
+        let parts = support::fixture_parts(&fixture);
+        let params = support::fixture_params(&fixture);
+        let lf = fs::read_to_string(&fixture).unwrap().replace("\r\n", "\n");
+        let crlf = lf.replace('\n', "\r\n");
+        let variants = [
+            ("lf", lf.clone()),
+            ("crlf", crlf.clone()),
+            ("lf-no-final-newline", String::from(lf.trim_end())),
+            ("crlf-no-final-newline", String::from(crlf.trim_end())),
+            ("lf-trailing-blank-lines", lf.clone() + "\n\n"),
+            ("crlf-trailing-blank-lines", crlf.clone() + "\r\n  \r\n")
+        ];
+
+        let expected = support::solve_file(day, &fixture, &parts, &params);
+        for (variant_name, text) in variants {
+            let variant_path = tmp_dir.join(format!("{variant_name}.txt"));
+            fs::write(&variant_path, text).unwrap();
+            let answers = support::solve_file(day, &variant_path, &parts, &params);
+            assert_eq!(answers, expected, "day {day:02}, {} as {variant_name}", fixture.display());
+        }
+    }
+
+    fs::remove_dir_all(&tmp_dir).unwrap();
+}
+
+macro_rules! line_ending_tests {
+    ($($name:ident: $day:expr),*) => {
+        $(
+            #[test]
+            fn $name() {
+                check_day($day);
+            }
+        )*
+    };
+}
+
+line_ending_tests!(
+    day01: 1, day02: 2, day03: 3, day04: 4, day05: 5,
+    day06: 6, day07: 7, day08: 8, day09: 9, day10: 10,
+    day11: 11, day12: 12, day13: 13, day14: 14, day15: 15,
+    day16: 16, day17: 17, day18: 18, day19: 19, day20: 20,
+    day21: 21, day22: 22, day23: 23, day24: 24, day25: 25
+);
@@ -0,0 +1,36 @@
+use aoc::days;
+use common::{input, params::Params, Answer, Overflow, Part, SolveError};
+
+mod support;
+
+// Answers that do not fit their type are errors rather than wrapped values
+#[test]
+fn reports_overflow() {
+    let solver = days::get(11);
+    let parsed = solver.parse(&input::load(11, support::fixture_dir(11).join("example.txt").to_str()).unwrap()).unwrap();
+    let mut params = Params::new();
+    params.set("expansion=1000000").unwrap();
+    assert_eq!(solver.solve(&*parsed, Part::Two, &params), Ok(Answer::UInt(82000210)));
+    params.set("expansion=1000000000000000000").unwrap();
+    assert_eq!(solver.solve(&*parsed, Part::Two, &params), Err(SolveError::Overflow(Overflow::new(11, "sum of distances"))));
+}
+
+#[test]
+fn describes_overflow() {
+    assert_eq!(Overflow::new(7, "total winnings").to_string(), "day 07: total winnings overflowed");
+    assert_eq!(SolveError::from(Overflow::new(7, "total winnings")).to_string(), "day 07: total winnings overflowed");
+    assert_eq!(SolveError::unsolvable(8, "no node AAA to start from").to_string(), "day 08: no node AAA to start from");
+}
+
+#[test]
+fn day05_reports_numbers_past_i64() {
+    let max = i64::MAX;
+    let error = day05::parse(&format!("seeds: 1 1\n\nseed-to-soil map:\n{max} -1 1\n")).err().unwrap().to_string();
+    assert!(error.contains("line 4, column 1") && error.contains("less than 2^63 apart"), "{error}");
+
+    let almanac = day05::parse(&format!("seeds: {} 2\n\nseed-to-soil map:\n{max} 0 {max}\n", max - 1)).unwrap();
+    assert_eq!(day05::part1(&almanac), Err(SolveError::Overflow(Overflow::new(5, "mapped number"))));
+    assert_eq!(day05::part2(&almanac), Err(SolveError::Overflow(Overflow::new(5, "end of a seed range"))));
+    let almanac = day05::parse(&format!("seeds: 20 1\n\nseed-to-soil map:\n0 10 {max}\n")).unwrap();
+    assert_eq!(day05::part1(&almanac), Err(SolveError::Overflow(Overflow::new(5, "end of a map range"))));
+}
@@ -0,0 +1,58 @@
+use aoc::days;
+use common::{params::Params, Answer, Part, SolveError};
+
+fn params(args: &[&str]) -> Params {
+    let mut params = Params::new();
+    for arg in args {
+        params.set(arg).unwrap();
+    }
+    return params;
+}
+
+#[test]
+fn accepts_declared_params() {
+    assert_eq!(params(&["steps=6"]).check(21, days::get(21).params()), Ok(()));
+    assert_eq!(params(&["min=7", "max=27"]).check(24, days::get(24).params()), Ok(()));
+    assert_eq!(params(&[]).check(5, days::get(5).params()), Ok(()));
+}
+
+#[test]
+fn rejects_unknown_and_invalid_params() {
+    assert!(params(&["steps=6"]).check(5, days::get(5).params()).is_err());
+    assert!(params(&["reds=1"]).check(2, days::get(2).params()).is_err());
+    assert!(params(&["red=-1"]).check(2, days::get(2).params()).is_err());
+    assert!(params(&["expansion=0"]).check(11, days::get(11).params()).is_err());
+    assert!(Params::new().set("steps").is_err());
+    assert!(Params::new().set("=6").is_err());
+}
+
+#[test]
+fn uses_defaults_for_missing_params() {
+    let params = params(&["steps=6"]);
+    assert_eq!(params.get_or("steps", 64), 6);
+    assert_eq!(params.get_or("infinite_steps", 26501365), 26501365);
+}
+
+#[test]
+fn day21_rejects_steps_that_do_not_end_at_a_map_edge() {
+    // On an open map, exactly n steps reach (n + 1)^2 plots
+    let solver = days::get(21);
+    let open = solver.parse(".....\n.....\n..S..\n.....\n.....\n").unwrap();
+    assert_eq!(solver.solve(&*open, Part::Two, &params(&["infinite_steps=12"])), Ok(Answer::UInt(169)));
+    let error = solver.solve(&*open, Part::Two, &params(&["infinite_steps=10"])).unwrap_err();
+    assert!(error.to_string().contains("10 steps is not a whole number of map widths plus half a width"), "{error}");
+    assert!(solver.solve(&*open, Part::Two, &params(&["infinite_steps=1"])).is_err());
+
+    // Walled in, the start is its own corner, and cutting it away leaves fewer than no plots
+    let walled = solver.parse(".....\n..#..\n.#S#.\n..#..\n.....\n").unwrap();
+    let error = solver.solve(&*walled, Part::Two, &params(&["infinite_steps=2"])).unwrap_err();
+    assert!(matches!(error, SolveError::Unsolvable { day: 21, .. }) && !error.to_string().contains("overflowed"), "{error}");
+}
+
+#[test]
+fn day14_runs_the_most_cycles_a_usize_holds() {
+    let solver = days::get(14);
+    let platform = solver.parse("O.\n..\n").unwrap();
+    let cycles = format!("cycles={}", usize::MAX);
+    assert_eq!(solver.solve(&*platform, Part::Two, &params(&[&cycles])), Ok(Answer::UInt(1)));
+}
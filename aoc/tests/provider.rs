@@ -0,0 +1,70 @@
+use std::fs;
+use aoc::provider::{Fetcher, HttpFetcher, InputCache, InputProvider};
+use support::stub_server::{self, Request};
+
+mod support;
+
+#[test]
+fn fetches_once_then_reads_the_cache() {
+    let (base_url, requests) = stub_server::start(vec![(200, "1abc2\r\npqr3stu8vwx\r\n")]);
+    let dir = stub_server::temp_dir("fetch-once");
+    let provider = InputProvider::new(InputCache::new(&dir), Some(Box::new(HttpFetcher::new(&base_url, "secret"))));
+
+    assert_eq!(provider.get(2023, 1).unwrap(), "1abc2\npqr3stu8vwx\n");
+    let request = Request { line: String::from("GET /2023/day/1/input HTTP/1.1"), cookie: String::from("session=secret"), body: String::new() };
+    assert_eq!(requests.recv().unwrap(), request);
+
+    // The stub only answers once, so a second fetch would fail
+    assert_eq!(provider.get(2023, 1).unwrap(), "1abc2\npqr3stu8vwx\n");
+    let cached_path = InputCache::new(&dir).path(2023, 1);
+    assert_eq!(fs::read_to_string(&cached_path).unwrap(), "1abc2\r\npqr3stu8vwx\r\n");
+    assert!(fs::metadata(&cached_path).unwrap().permissions().readonly());
+    assert!(InputCache::new(&dir).store(2023, 1, "replaced\n").is_err());
+    assert_eq!(fs::read_to_string(&cached_path).unwrap(), "1abc2\r\npqr3stu8vwx\r\n");
+
+    // The temporary file the input was written to has been renamed into place, or removed after the failed store
+    let entries: Vec<_> = fs::read_dir(cached_path.parent().unwrap()).unwrap().map(|e| e.unwrap().file_name()).collect();
+    assert_eq!(entries, ["day01.txt"]);
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn reports_http_errors_without_caching() {
+    let (base_url, _requests) = stub_server::start(vec![(404, "Please don't repeatedly request this endpoint before it unlocks!")]);
+    let dir = stub_server::temp_dir("http-error");
+    let fetcher = HttpFetcher::new(&format!("{base_url}/"), "secret");
+    assert_eq!(fetcher.url(2023, 25), format!("{base_url}/2023/day/25/input"));
+
+    let provider = InputProvider::new(InputCache::new(&dir), Some(Box::new(fetcher)));
+    let error = provider.get(2023, 25).unwrap_err();
+    assert!(error.contains("404"), "{error}");
+    assert_eq!(InputCache::new(&dir).get(2023, 25), Ok(None));
+}
+
+#[test]
+fn works_offline_from_the_cache_alone() {
+    let dir = stub_server::temp_dir("offline");
+    let cache = InputCache::new(&dir);
+    cache.store(2023, 9, "0 3 6 9 12 15\n").unwrap();
+
+    let provider = InputProvider::new(InputCache::new(&dir), None);
+    assert_eq!(provider.get(2023, 9).unwrap(), "0 3 6 9 12 15\n");
+    assert!(provider.get(2023, 10).unwrap_err().contains("not cached"));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+// Fetchers other than HTTP can be plugged in, for example to read inputs from somewhere else
+#[test]
+fn uses_any_fetcher() {
+    struct Canned;
+    impl Fetcher for Canned {
+        fn fetch(&self, year: u32, day: u32) -> Result<String, String> {
+            return Ok(format!("{year} {day}\n"));
+        }
+    }
+    let dir = stub_server::temp_dir("canned");
+    let provider = InputProvider::new(InputCache::new(&dir), Some(Box::new(Canned)));
+    assert_eq!(provider.get(2022, 3).unwrap(), "2022 3\n");
+    assert_eq!(InputCache::new(&dir).get(2022, 3), Ok(Some(String::from("2022 3\n"))));
+    fs::remove_dir_all(&dir).unwrap();
+}
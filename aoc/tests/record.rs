@@ -0,0 +1,18 @@
+use std::time::Duration;
+use aoc::record::{json_string, Record};
+use common::Part;
+
+#[test]
+fn writes_solved_and_failed_records() {
+    let solved = Record::solved(7, Part::Two, "5905", Duration::from_nanos(1234));
+    assert_eq!(solved.to_json(), "{\"day\": 7, \"part\": 2, \"answer\": \"5905\", \"elapsed_ns\": 1234, \"error\": null}");
+    let failed = Record::failed(12, Part::One, "invalid input");
+    assert_eq!(failed.to_json(), "{\"day\": 12, \"part\": 1, \"answer\": null, \"elapsed_ns\": null, \"error\": \"invalid input\"}");
+}
+
+#[test]
+fn escapes_strings() {
+    assert_eq!(json_string("plain"), "\"plain\"");
+    assert_eq!(json_string("found \"x\" at C:\\in"), "\"found \\\"x\\\" at C:\\\\in\"");
+    assert_eq!(json_string("a\nb\tc\u{1}"), "\"a\\nb\\tc\\u0001\"");
+}
@@ -0,0 +1,82 @@
+use std::{fs, path::Path};
+use aoc::scaffold;
+
+mod support;
+
+fn workspace_file(relative_path: &str) -> String {
+    return fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(relative_path)).unwrap();
+}
+
+// The edits must keep understanding the workspace's own files as they change
+#[test]
+fn registers_a_day_after_the_last() {
+    let cargo_toml = workspace_file("Cargo.toml");
+    let expected = cargo_toml.replace("\"day25\",\n]", "\"day25\",\n    \"day26\",\n]");
+    assert_eq!(scaffold::add_workspace_member(&cargo_toml, 26).unwrap(), expected);
+
+    let aoc_toml = workspace_file("aoc/Cargo.toml");
+    let expected = aoc_toml.replace("day25 = { path = \"../day25\" }\n", "day25 = { path = \"../day25\" }\nday26 = { path = \"../day26\" }\n");
+    assert_eq!(scaffold::add_dependency(&aoc_toml, 26).unwrap(), expected);
+
+    let days_rs = workspace_file("aoc/src/days.rs");
+    let expected = days_rs.replace("NUM_DAYS: u32 = 25", "NUM_DAYS: u32 = 26").replace("&day25::Day25\n", "&day25::Day25,\n    &day26::Day26\n");
+    assert_eq!(scaffold::add_solver(&days_rs, 26).unwrap(), expected);
+
+    for tests_path in ["aoc/tests/examples.rs", "aoc/tests/line_endings.rs"] {
+        let tests_rs = workspace_file(tests_path);
+        let expected = tests_rs.replace("day25: 25\n", "day25: 25,\n    day26: 26\n");
+        assert_eq!(scaffold::add_test(&tests_rs, 26).unwrap(), expected, "{tests_path}");
+    }
+}
+
+#[test]
+fn rejects_days_out_of_order() {
+    let days_rs = workspace_file("aoc/src/days.rs");
+    assert!(scaffold::add_solver(&days_rs, 25).unwrap_err().contains("already registered"));
+    assert!(scaffold::add_solver(&days_rs, 27).unwrap_err().contains("before day 26"));
+    assert!(scaffold::add_test(&workspace_file("aoc/tests/examples.rs"), 3).is_err());
+    assert!(scaffold::add_workspace_member(&workspace_file("Cargo.toml"), 1).is_err());
+    assert!(scaffold::add_dependency(&workspace_file("aoc/Cargo.toml"), 30).is_err());
+}
+
+// A workspace for a new year, before any days have been added
+fn write_empty_workspace(root: &Path) {
+    fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+    fs::create_dir_all(root.join("aoc").join("tests")).unwrap();
+    fs::write(root.join("Cargo.toml"), "[workspace]\nresolver = \"2\"\nmembers = [\n    \"aoc\",\n    \"common\",\n]\n").unwrap();
+    fs::write(root.join("aoc/Cargo.toml"), "[package]\nname = \"aoc\"\n\n[dependencies]\ncommon = { path = \"../common\" }\ntoml = \"0.8\"\n\n[lints]\nworkspace = true\n").unwrap();
+    fs::write(root.join("aoc/src/days.rs"), "pub const NUM_DAYS: u32 = 0;\n\npub const SOLVERS: [&dyn Solver; NUM_DAYS as usize] = [\n];\n").unwrap();
+    for tests_file in ["examples.rs", "line_endings.rs"] {
+        fs::write(root.join("aoc/tests").join(tests_file), "example_tests!(\n);\n").unwrap();
+    }
+}
+
+#[test]
+fn creates_the_first_day_of_a_workspace() {
+    let root = support::stub_server::temp_dir("scaffold");
+    write_empty_workspace(&root);
+    let written = scaffold::create_day(&root, 1, "Trebuchet?!").unwrap();
+    assert_eq!(written.len(), 10);
+
+    assert_eq!(fs::read_to_string(root.join("Cargo.toml")).unwrap(), "[workspace]\nresolver = \"2\"\nmembers = [\n    \"aoc\",\n    \"common\",\n    \"day01\",\n]\n");
+    assert!(fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap().contains("common = { path = \"../common\" }\nday01 = { path = \"../day01\" }\ntoml"));
+    assert_eq!(fs::read_to_string(root.join("aoc/src/days.rs")).unwrap(),
+               "pub const NUM_DAYS: u32 = 1;\n\npub const SOLVERS: [&dyn Solver; NUM_DAYS as usize] = [\n    &day01::Day01\n];\n");
+    assert_eq!(fs::read_to_string(root.join("aoc/tests/examples.rs")).unwrap(), "example_tests!(\n    day01: 1\n);\n");
+
+    let lib_rs = fs::read_to_string(root.join("day01/src/lib.rs")).unwrap();
+    assert!(lib_rs.starts_with("//! Day 1: Trebuchet?!\n"));
+    assert!(lib_rs.contains("pub struct Day01;") && lib_rs.contains("const DAY: u32 = 1;"));
+    assert!(!lib_rs.contains("__"));
+    assert!(fs::read_to_string(root.join("day01/src/main.rs")).unwrap().contains("use day01::Day01;"));
+    assert!(fs::read_to_string(root.join("day01/Cargo.toml")).unwrap().contains("name = \"day01\""));
+    assert_eq!(fs::read_to_string(root.join("day01/fixtures/example.txt")).unwrap(), "");
+
+    // Nothing is touched when the day cannot be added
+    let cargo_toml = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+    assert!(scaffold::create_day(&root, 1, "Again").unwrap_err().contains("already exists"));
+    assert!(scaffold::create_day(&root, 3, "Gear Ratios").unwrap_err().contains("before day 2"));
+    assert!(!root.join("day03").exists());
+    assert_eq!(fs::read_to_string(root.join("Cargo.toml")).unwrap(), cargo_toml);
+    fs::remove_dir_all(&root).unwrap();
+}
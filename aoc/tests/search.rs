@@ -0,0 +1,67 @@
+// The shortest path searches shared by the days
+
+use common::{parse, search, Grid};
+
+// A maze with a short way round to the right and a long way round to the left
+const MAZE: &str = "\
+S.#....
+#.#.##.
+#...#..
+###.#.#
+#.....E
+";
+
+fn maze() -> Grid<char> {
+    return parse::char_grid(1, MAZE, ".#SE").unwrap();
+}
+
+fn open_neighbors(grid: &Grid<char>, pos: (usize, usize)) -> Vec<(usize, usize)> {
+    return grid.neighbors4(pos).filter(|n| grid[*n] != '#').collect();
+}
+
+#[test]
+fn bfs_finds_shortest_paths() {
+    let grid = maze();
+    let end = grid.position(|c| *c == 'E').unwrap();
+    let paths = search::bfs((0, 0), |pos| open_neighbors(&grid, *pos));
+    assert_eq!(paths.distance(&end), Some(10));
+    assert_eq!(paths.distance(&(0, 6)), Some(10));
+    let path = paths.path_to(&end).unwrap();
+    assert_eq!(path.len(), 11);
+    assert_eq!((path[0], path[10]), ((0, 0), end));
+    assert!(path.windows(2).all(|w| grid.neighbors4(w[0]).any(|n| n == w[1])));
+    assert_eq!(paths.predecessor(&(0, 0)), None);
+    assert_eq!(paths.path_to(&(0, 2)), None);
+}
+
+#[test]
+fn dijkstra_and_astar_agree() {
+    // Stepping onto a tile costs its row number plus one, so the lower route is dearer
+    let grid = maze();
+    let end = grid.position(|c| *c == 'E').unwrap();
+    let neighbors = |pos: &(usize, usize)| open_neighbors(&grid, *pos).into_iter().map(|n| (n, n.0 + 1)).collect::<Vec<_>>();
+    let dijkstra = search::dijkstra((0, 0), neighbors, |pos| *pos == end);
+    let astar = search::astar((0, 0), neighbors, |pos| (end.0 - pos.0) + (end.1 - pos.1), |pos| *pos == end);
+    assert_eq!(dijkstra.goal(), Some(&end));
+    assert_eq!(astar.goal(), Some(&end));
+    assert_eq!(dijkstra.distance(&end), astar.distance(&end));
+    let path = astar.path_to(&end).unwrap();
+    let cost: usize = path.iter().skip(1).map(|pos| pos.0 + 1).sum();
+    assert_eq!(astar.distance(&end), Some(cost));
+
+    // Without a goal, every reachable tile is settled
+    let everything = search::dijkstra((0, 0), neighbors, |_| false);
+    assert_eq!(everything.goal(), None);
+    assert_eq!(everything.distances().count(), MAZE.chars().filter(|c| *c != '#' && *c != '\n').count());
+}
+
+#[test]
+fn zero_one_bfs_counts_costly_steps() {
+    // Walls can be broken through at a cost of 1, and open tiles are free
+    let grid = maze();
+    let end = grid.position(|c| *c == 'E').unwrap();
+    let paths = search::zero_one_bfs((0, 0), |pos| grid.neighbors4(*pos).map(|n| (n, if grid[n] == '#' { 1 } else { 0 })).collect::<Vec<_>>());
+    assert_eq!(paths.distance(&end), Some(0));
+    assert_eq!(paths.distance(&(1, 0)), Some(1));
+    assert_eq!(paths.distance(&(0, 2)), Some(1));
+}
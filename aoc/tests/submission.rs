@@ -0,0 +1,108 @@
+use std::time::Duration;
+use aoc::submission::{parse_response, Outcome, Submission, SubmissionLog, Submitter, COOLDOWN};
+use common::Part;
+use support::stub_server::{self, Request};
+
+mod support;
+
+// Trimmed down from the pages the site returns
+const RIGHT: &str = "<main><article><p>That's the right answer!  You are one gold star closer to restoring snow operations.</p></article></main>";
+const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.</p></article></main>";
+const TOO_LOW: &str = "<main><article><p>That's not the right answer; your answer is too low.  Please wait one minute before trying again.</p></article></main>";
+const WRONG: &str = "<main><article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.</p></article></main>";
+const TOO_RECENT: &str = "<main><article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 30s left to wait.</p></article></main>";
+const WRONG_LEVEL: &str = "<main><article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article></main>";
+
+#[test]
+fn parses_response_pages() {
+    assert_eq!(parse_response(RIGHT), Outcome::Correct);
+    assert_eq!(parse_response(TOO_HIGH), Outcome::TooHigh);
+    assert_eq!(parse_response(TOO_LOW), Outcome::TooLow);
+    assert_eq!(parse_response(WRONG), Outcome::Incorrect);
+    assert_eq!(parse_response(TOO_RECENT), Outcome::RateLimited(Some(Duration::from_secs(90))));
+    assert_eq!(parse_response("You gave an answer too recently. You have 45s left to wait."), Outcome::RateLimited(Some(Duration::from_secs(45))));
+    assert_eq!(parse_response("You gave an answer too recently."), Outcome::RateLimited(None));
+    assert_eq!(parse_response("You gave an answer too recently. You have 1m 30秒 left to wait."), Outcome::RateLimited(None));
+    assert_eq!(parse_response("You gave an answer too recently. You have  left to wait."), Outcome::RateLimited(None));
+    assert_eq!(parse_response("You gave an answer too recently. You have s left to wait."), Outcome::RateLimited(None));
+    assert_eq!(parse_response(WRONG_LEVEL), Outcome::WrongLevel);
+    assert_eq!(parse_response("<html>Internal error</html>"), Outcome::Unrecognized);
+}
+
+#[test]
+fn posts_answers_to_the_stub() {
+    let (base_url, requests) = stub_server::start(vec![(200, TOO_LOW), (200, RIGHT)]);
+    let submitter = Submitter::new(&base_url, "secret");
+    assert_eq!(submitter.submit(2023, 7, Part::Two, "5904"), Ok(Outcome::TooLow));
+    assert_eq!(submitter.submit(2023, 7, Part::Two, "5905"), Ok(Outcome::Correct));
+
+    let first = Request { line: String::from("POST /2023/day/7/answer HTTP/1.1"), cookie: String::from("session=secret"), body: String::from("level=2&answer=5904") };
+    assert_eq!(requests.recv().unwrap(), first);
+    assert_eq!(requests.recv().unwrap().body, "level=2&answer=5905");
+}
+
+#[test]
+fn reports_server_errors() {
+    let (base_url, _requests) = stub_server::start(vec![(500, "")]);
+    let error = Submitter::new(&base_url, "secret").submit(2023, 1, Part::One, "142").unwrap_err();
+    assert!(error.contains("500"), "{error}");
+}
+
+fn submission(time: u64, answer: &str, outcome: Outcome) -> Submission {
+    return Submission { time: time, day: 1, part: Part::One, answer: String::from(answer), outcome: outcome };
+}
+
+#[test]
+fn enforces_the_cooldown() {
+    let mut log = SubmissionLog::default();
+    assert_eq!(log.wait_time(1000), None);
+    log.record(submission(1000, "500", Outcome::TooHigh));
+    assert_eq!(log.wait_time(1010), Some(COOLDOWN - Duration::from_secs(10)));
+    assert_eq!(log.wait_time(1060), None);
+
+    // A longer wait asked for by the site takes precedence
+    log.record(submission(1100, "100", Outcome::RateLimited(Some(Duration::from_secs(300)))));
+    assert_eq!(log.wait_time(1200), Some(Duration::from_secs(200)));
+    assert_eq!(log.wait_time(1400), None);
+
+    // A wait too long to add to the time of the submission never ends
+    log.record(submission(1500, "200", Outcome::RateLimited(Some(Duration::from_secs(u64::MAX)))));
+    assert_eq!(log.wait_time(u64::MAX), Some(Duration::MAX));
+}
+
+#[test]
+fn refuses_answers_known_to_be_wrong() {
+    let mut log = SubmissionLog::default();
+    log.record(submission(1000, "500", Outcome::TooHigh));
+    log.record(submission(1100, "100", Outcome::TooLow));
+    log.record(submission(1200, "300", Outcome::Incorrect));
+    assert!(log.known_wrong(1, Part::One, "500").is_some());
+    assert!(log.known_wrong(1, Part::One, "600").is_some());
+    assert!(log.known_wrong(1, Part::One, "50").is_some());
+    assert!(log.known_wrong(1, Part::One, "300").is_some());
+    assert_eq!(log.known_wrong(1, Part::One, "250"), None);
+    assert_eq!(log.known_wrong(1, Part::Two, "500"), None);
+    assert_eq!(log.known_wrong(2, Part::One, "500"), None);
+
+    log.record(submission(1300, "250", Outcome::Correct));
+    assert_eq!(log.accepted(1, Part::One), Some("250"));
+    assert_eq!(log.known_wrong(1, Part::One, "250"), None);
+    assert!(log.known_wrong(1, Part::One, "251").is_some());
+}
+
+#[test]
+fn round_trips_through_toml() {
+    let mut log = SubmissionLog::default();
+    log.record(submission(1701406920, "54304", Outcome::Correct));
+    log.record(Submission { time: 1701493320, day: 2, part: Part::Two, answer: String::from("72706"),
+                            outcome: Outcome::RateLimited(Some(Duration::from_secs(45))) });
+    let toml_str = log.to_toml();
+    assert!(toml_str.starts_with("[[submission]]\n"), "{toml_str}");
+    assert!(toml_str.ends_with("outcome = \"rate limited\"\npart = 2\ntime = 1701493320\nwait = 45\n"), "{toml_str}");
+    assert_eq!(SubmissionLog::parse(&toml_str).unwrap(), log);
+    assert_eq!(SubmissionLog::parse("").unwrap(), SubmissionLog::default());
+    assert!(SubmissionLog::parse("[[submission]]\ntime = 1\nday = 1\npart = 3\nanswer = \"1\"\noutcome = \"correct\"\n").is_err());
+    let error = SubmissionLog::parse("[[submission]]\ntime = 1\nday = 1\npart = 1\nanswer = \"1\"\noutcome = \"rate limited\"\nwait = -5\n").unwrap_err();
+    assert!(error.contains("non-negative integer 'wait'"), "{error}");
+    assert!(SubmissionLog::parse("[[submission]]\ntime = 1\nday = 1\npart = 1\nanswer = \"1\"\noutcome = \"maybe\"\n").is_err());
+}
@@ -0,0 +1,56 @@
+// Fixture discovery shared by the integration tests
+//
+// Each day keeps its fixtures in dayNN/fixtures. A fixture is an input file NAME.txt, optionally with a
+// NAME.answers file next to it in the format described in common::example
+
+#![allow(dead_code)] // Each test binary only uses some of these
+
+pub mod stub_server;
+
+use std::{fs, path::{Path, PathBuf}};
+use aoc::days;
+use common::{example::Example, input, params::Params, Answer, Part};
+
+pub fn fixture_dir(day: u32) -> PathBuf {
+    return Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(format!("day{day:02}")).join("fixtures");
+}
+
+// All input files for the given day, sorted by name
+pub fn fixtures(day: u32) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(fixture_dir(day)).unwrap()
+                                                                .map(|e| e.unwrap().path())
+                                                                .filter(|p| p.extension().is_some_and(|ext| ext == "txt"))
+                                                                .collect();
+    paths.sort();
+    return paths;
+}
+
+// A fixture as the Example a day would embed it as, so that answers files are read exactly as `aoc run --example`
+// reads them. The text is leaked to give it the static lifetime of an embedded example
+pub fn fixture_example(path: &Path) -> Example {
+    let name = path.file_stem().unwrap().to_str().unwrap();
+    let answers = fs::read_to_string(path.with_extension("answers")).unwrap_or_default();
+    return Example { name: String::from(name).leak(), input: fs::read_to_string(path).unwrap().leak(), answers: answers.leak() };
+}
+
+pub fn fixture_parts(path: &Path) -> Vec<Part> {
+    return fixture_example(path).parts();
+}
+
+// Known answers for a fixture, empty if it has no answers file
+pub fn expected_answers(path: &Path) -> Vec<(Part, String)> {
+    return fixture_example(path).expected().unwrap_or_else(|e| panic!("{}: {e}", path.with_extension("answers").display()));
+}
+
+// Parameters a fixture is solved with, default unless its answers file sets some
+pub fn fixture_params(path: &Path) -> Params {
+    return fixture_example(path).params().unwrap_or_else(|e| panic!("{}: {e}", path.with_extension("answers").display()));
+}
+
+pub fn solve_file(day: u32, path: &Path, parts: &[Part], params: &Params) -> Vec<Answer> {
+    let input = input::load(day, path.to_str()).unwrap();
+    let solver = days::get(day);
+    params.check(day, solver.params()).unwrap();
+    let parsed = solver.parse(&input).unwrap();
+    return parts.iter().map(|part| solver.solve(&*parsed, *part, params).unwrap_or_else(|e| panic!("{}: {e}", path.display()))).collect();
+}
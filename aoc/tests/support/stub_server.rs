@@ -0,0 +1,59 @@
+// A stand-in for the puzzle site, so that the HTTP clients can be tested without any network access
+
+use std::{env, fs, io::{BufRead, BufReader, Read, Write}, net::TcpListener, path::PathBuf, process, sync::mpsc, thread};
+
+// What the stub saw of one request
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Request {
+    pub line: String, // e.g. "GET /2023/day/1/input HTTP/1.1"
+    pub cookie: String,
+    pub body: String
+}
+
+// Serves one canned (status, body) response per connection, in order, and reports each request it answered
+// Returns the base URL to point a client at
+pub fn start(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<Request>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for (status, body) in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            let mut cookie = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                let Some((name, value)) = header.split_once(':') else {
+                    continue;
+                };
+                if name.eq_ignore_ascii_case("cookie") {
+                    cookie = String::from(value.trim());
+                }
+                else if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+            let mut request_body = vec![0; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+
+            write!(stream, "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len()).unwrap();
+            let request = Request { line: String::from(line.trim()), cookie: cookie, body: String::from_utf8(request_body).unwrap() };
+            sender.send(request).unwrap();
+        }
+    });
+    return (base_url, receiver);
+}
+
+// A fresh, empty directory for one test, so that tests running in parallel do not share files
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-test-{}-{name}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    return dir;
+}
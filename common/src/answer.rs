@@ -0,0 +1,64 @@
+use std::fmt;
+
+// Puzzle answer returned by either part of any day
+// Days produce different integer types, so each is widened into one of these variants
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    UInt(u64),
+    Wide(i128),
+    Text(String)
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::UInt(n) => write!(f, "{n}"),
+            Answer::Wide(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}")
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(n: i32) -> Self {
+        return Answer::Int(n.into());
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        return Answer::Int(n);
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Self {
+        return Answer::UInt(n.into());
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        return Answer::UInt(n);
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        return Answer::UInt(n as u64);
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(n: i128) -> Self {
+        return Answer::Wide(n);
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        return Answer::Text(s);
+    }
+}
@@ -0,0 +1,77 @@
+use std::{error::Error, fmt};
+
+// Malformed puzzle input, pointing at where the parser gave up
+// Lines and columns count from 1, and line is one past the last line when the input ended too early
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u32,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {:02}, line {}, column {}: expected {}", self.day, self.line, self.column, self.expected)?;
+        if self.found.is_empty() {
+            return write!(f, ", found nothing");
+        }
+        return write!(f, ", found {:?}", self.found);
+    }
+}
+
+impl Error for ParseError {}
+
+// Arithmetic on an answer that did not fit its type, reported instead of wrapping or panicking
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Overflow {
+    pub day: u32,
+    pub quantity: String
+}
+
+impl Overflow {
+    pub fn new(day: u32, quantity: &str) -> Overflow {
+        return Overflow { day: day, quantity: String::from(quantity) };
+    }
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "day {:02}: {} overflowed", self.day, self.quantity);
+    }
+}
+
+impl Error for Overflow {}
+
+// Why a part gave no answer for input that parsed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SolveError {
+    Overflow(Overflow),
+    // The input or parameters are well formed, but not something the part can answer, such as a network without the
+    // node its path starts from
+    Unsolvable { day: u32, reason: String }
+}
+
+impl SolveError {
+    pub fn unsolvable(day: u32, reason: &str) -> SolveError {
+        return SolveError::Unsolvable { day: day, reason: String::from(reason) };
+    }
+}
+
+impl From<Overflow> for SolveError {
+    fn from(overflow: Overflow) -> SolveError {
+        return SolveError::Overflow(overflow);
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            SolveError::Overflow(overflow) => overflow.fmt(f),
+            SolveError::Unsolvable { day, reason } => write!(f, "day {day:02}: {reason}")
+        };
+    }
+}
+
+impl Error for SolveError {}
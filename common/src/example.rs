@@ -0,0 +1,78 @@
+use crate::{params::Params, Part};
+
+// A worked example from the puzzle text, embedded in the day's crate from dayNN/fixtures/NAME.txt and NAME.answers
+// The answers text lists the known answers as "part1: ..." and "part2: ..." lines, and "param: name=value" lines set
+// the day's parameters for examples that use different constants from the real puzzle. Lines starting with '#'
+// are comments
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub name: &'static str,
+    pub input: &'static str,
+    pub answers: &'static str
+}
+
+impl Example {
+    // example-partN examples only apply to part N, any other example applies to both parts
+    pub fn parts(&self) -> Vec<Part> {
+        if self.name.starts_with("example-part1") {
+            return vec![Part::One];
+        }
+        else if self.name.starts_with("example-part2") {
+            return vec![Part::Two];
+        }
+        return Part::ALL.to_vec();
+    }
+
+    // Known answers, which may be fewer than the parts the example applies to
+    pub fn expected(&self) -> Result<Vec<(Part, String)>, String> {
+        let mut answers = Vec::new();
+        for (key, value) in answers_entries(self.answers)? {
+            match key.as_str() {
+                "part1" => answers.push((Part::One, value)),
+                "part2" => answers.push((Part::Two, value)),
+                _ => ()
+            }
+        }
+        return Ok(answers);
+    }
+
+    pub fn expected_for(&self, part: Part) -> Result<Option<String>, String> {
+        return Ok(self.expected()?.into_iter().find(|(p, _)| *p == part).map(|(_, answer)| answer));
+    }
+
+    // Parameters the example is solved with, default unless its answers set some
+    pub fn params(&self) -> Result<Params, String> {
+        let mut params = Params::new();
+        for (key, value) in answers_entries(self.answers)? {
+            if key == "param" {
+                params.set(&value)?;
+            }
+        }
+        return Ok(params);
+    }
+}
+
+// The "key: value" lines of an answers file
+pub fn answers_entries(answers_str: &str) -> Result<Vec<(String, String)>, String> {
+    let mut entries = Vec::new();
+    for ln in answers_str.lines().map(|l| l.trim()).filter(|l| !l.is_empty() && !l.starts_with('#')) {
+        let (key, value) = ln.split_once(':').ok_or_else(|| format!("expected 'partN: answer', found {ln:?}"))?;
+        match key.trim() {
+            "part1" | "part2" | "param" => entries.push((String::from(key.trim()), String::from(value.trim()))),
+            _ => return Err(format!("expected part1, part2 or param, found {key:?}"))
+        }
+    }
+    return Ok(entries);
+}
+
+// Embeds fixtures/NAME.txt and fixtures/NAME.answers of the crate that invokes it as an Example
+#[macro_export]
+macro_rules! example {
+    ($name:literal) => {
+        $crate::example::Example {
+            name: $name,
+            input: include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/", $name, ".txt")),
+            answers: include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/", $name, ".answers"))
+        }
+    };
+}
@@ -0,0 +1,186 @@
+use std::{fmt, ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign}};
+
+// One of the four ways to move on a grid, where up is towards the first row
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right
+}
+
+impl Direction {
+    // In the same order as grid::NEIGHBORS4
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Down, Direction::Left, Direction::Right];
+
+    // Position in ALL, for keeping a value per direction in an array
+    pub fn index(self) -> usize {
+        return self as usize;
+    }
+
+    // The direction after a quarter turn counterclockwise
+    pub fn turn_left(self) -> Direction {
+        return match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up
+        };
+    }
+
+    // The direction after a quarter turn clockwise
+    pub fn turn_right(self) -> Direction {
+        return self.turn_left().reverse();
+    }
+
+    pub fn reverse(self) -> Direction {
+        return match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left
+        };
+    }
+
+    pub fn is_vertical(self) -> bool {
+        return self == Direction::Up || self == Direction::Down;
+    }
+
+    // Row and column offset of one step, as taken by Grid::step
+    pub fn offset(self) -> (isize, isize) {
+        return match self {
+            Direction::Up => (-1, 0),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+            Direction::Right => (0, 1)
+        };
+    }
+
+    // One step as a point, to add to positions off the grid
+    pub fn unit(self) -> Point {
+        let (d_row, d_col) = self.offset();
+        return Point::new(d_row as i64, d_col as i64);
+    }
+
+    // Reads the letters U, D, L and R, or the compass points N, S, W and E
+    pub fn from_letter(letter: char) -> Option<Direction> {
+        return match letter {
+            'U' | 'N' => Some(Direction::Up),
+            'D' | 'S' => Some(Direction::Down),
+            'L' | 'W' => Some(Direction::Left),
+            'R' | 'E' => Some(Direction::Right),
+            _ => None
+        };
+    }
+
+    pub fn letter(self) -> char {
+        return match self {
+            Direction::Up => 'U',
+            Direction::Down => 'D',
+            Direction::Left => 'L',
+            Direction::Right => 'R'
+        };
+    }
+
+    // Reads the digits 0 to 3 as right, down, left and up, clockwise from right
+    pub fn from_digit(digit: char) -> Option<Direction> {
+        return match digit {
+            '0' => Some(Direction::Right),
+            '1' => Some(Direction::Down),
+            '2' => Some(Direction::Left),
+            '3' => Some(Direction::Up),
+            _ => None
+        };
+    }
+
+    pub fn digit(self) -> char {
+        return match self {
+            Direction::Right => '0',
+            Direction::Down => '1',
+            Direction::Left => '2',
+            Direction::Up => '3'
+        };
+    }
+}
+
+// Prints the U, D, L or R letter
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}", self.letter());
+    }
+}
+
+// A position or displacement that can leave the grid in any direction, as a row and column like grid positions
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: i64,
+    pub col: i64
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { row: 0, col: 0 };
+
+    pub fn new(row: i64, col: i64) -> Point {
+        return Point { row: row, col: col };
+    }
+
+    // Steps from the origin when only moving up, down, left and right
+    pub fn manhattan(self) -> i64 {
+        return self.row.abs() + self.col.abs();
+    }
+
+    // The grid position, if the point is not above or left of the grid
+    pub fn to_grid(self) -> Option<(usize, usize)> {
+        return Some((usize::try_from(self.row).ok()?, usize::try_from(self.col).ok()?));
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((row, col): (usize, usize)) -> Point {
+        return Point::new(row as i64, col as i64);
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        return Point::new(self.row + other.row, self.col + other.col);
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        return Point::new(self.row - other.row, self.col - other.col);
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i64) -> Point {
+        return Point::new(self.row * factor, self.col * factor);
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        return Point::new(-self.row, -self.col);
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
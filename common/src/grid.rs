@@ -0,0 +1,156 @@
+use std::{fmt, ops::{Index, IndexMut}};
+
+// Row and column offsets to the tiles up, down, left and right of a tile
+pub const NEIGHBORS4: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+// Offsets to all eight surrounding tiles, row by row
+pub const NEIGHBORS8: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+// A rectangular grid of cells, indexed by (row, column) from the top left
+// Cells are stored row by row, so whole rows are slices
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> where T: Clone {
+        return Grid { width: width, height: height, cells: vec![fill; width * height] };
+    }
+
+    // Builds each cell from its position
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut((usize, usize)) -> T) -> Grid<T> {
+        let mut cells = Vec::with_capacity(width * height);
+        for row in 0..height {
+            for col in 0..width {
+                cells.push(cell((row, col)));
+            }
+        }
+        return Grid { width: width, height: height, cells: cells };
+    }
+
+    // Panics if the rows are not all the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |r| r.len());
+        let mut cells = Vec::with_capacity(width * height);
+        for row in rows {
+            assert_eq!(row.len(), width, "Grid rows must all have the same length");
+            cells.extend(row);
+        }
+        return Grid { width: width, height: height, cells: cells };
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    pub fn contains(&self, (row, col): (usize, usize)) -> bool {
+        return row < self.height && col < self.width;
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        return if self.contains(pos) { Some(&self.cells[pos.0 * self.width + pos.1]) } else { None };
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        return if self.contains(pos) { Some(&mut self.cells[pos.0 * self.width + pos.1]) } else { None };
+    }
+
+    // The position the given (row, column) offset away, if it is still on the grid
+    pub fn step(&self, (row, col): (usize, usize), (d_row, d_col): (isize, isize)) -> Option<(usize, usize)> {
+        let next = (row.checked_add_signed(d_row)?, col.checked_add_signed(d_col)?);
+        return if self.contains(next) { Some(next) } else { None };
+    }
+
+    // Positions up, down, left and right of pos that are on the grid, in that order
+    pub fn neighbors4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        return NEIGHBORS4.iter().filter_map(move |offset| self.step(pos, *offset));
+    }
+
+    // Positions around pos, including diagonally, that are on the grid
+    pub fn neighbors8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        return NEIGHBORS8.iter().filter_map(move |offset| self.step(pos, *offset));
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        return &self.cells[(row * self.width)..((row + 1) * self.width)];
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        return self.cells.chunks(self.width.max(1));
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        return (0..self.height).map(move |row| &self[(row, col)]);
+    }
+
+    // Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        return (0..(self.width * self.height)).map(move |i| (i / width, i % width));
+    }
+
+    // Every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        return self.positions().zip(self.cells.iter());
+    }
+
+    // Position of the first cell, row by row, that matches
+    pub fn position(&self, matches: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        return self.cells.iter().position(matches).map(|i| (i / self.width, i % self.width));
+    }
+
+    pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        return Grid { width: self.width, height: self.height, cells: self.cells.iter().map(cell).collect() };
+    }
+
+    // Mirrors the grid along its main diagonal, so rows become columns
+    pub fn transpose(&self) -> Grid<T> where T: Clone {
+        return Grid::from_fn(self.height, self.width, |(row, col)| self[(col, row)].clone());
+    }
+
+    // Turns the grid a quarter turn clockwise, so the left column becomes the top row
+    pub fn rotate_clockwise(&self) -> Grid<T> where T: Clone {
+        return Grid::from_fn(self.height, self.width, |(row, col)| self[(self.height - 1 - col, row)].clone());
+    }
+
+    // Turns the grid a quarter turn counterclockwise, so the top row becomes the left column
+    pub fn rotate_counterclockwise(&self) -> Grid<T> where T: Clone {
+        return Grid::from_fn(self.height, self.width, |(row, col)| self[(col, self.width - 1 - row)].clone());
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(col < self.width, "Column {col} is outside a grid of width {}", self.width);
+        return &self.cells[row * self.width + col];
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(col < self.width, "Column {col} is outside a grid of width {}", self.width);
+        return &mut self.cells[row * self.width + col];
+    }
+}
+
+// One line per row, each ending in a newline, so a grid of characters prints as the text it was parsed from
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        return Ok(());
+    }
+}
@@ -0,0 +1,69 @@
+use std::{env, fmt, fs, io::{self, Read}, path::PathBuf};
+use crate::log;
+
+// Where a day's puzzle input is read from
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    Path(PathBuf)
+}
+
+impl InputSource {
+    // `-` means stdin, any other argument is a file path, and no argument means inputs/dayNN.txt
+    pub fn from_arg(day: u32, arg: Option<&str>) -> InputSource {
+        return match arg {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::Path(PathBuf::from(path)),
+            None => InputSource::Path(default_path(day))
+        };
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        let result = match self {
+            InputSource::Stdin => {
+                let mut input_str = String::new();
+                io::stdin().read_to_string(&mut input_str).map(|_| input_str)
+            },
+            InputSource::Path(path) => fs::read_to_string(path)
+        };
+        return result.map(|s| normalize(&s)).map_err(|e| io::Error::new(e.kind(), format!("{self}: {e}")));
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::Path(path) => write!(f, "{}", path.display())
+        }
+    }
+}
+
+// Puts input text into the one form every parser expects: LF line endings, whitespace-only lines emptied,
+// no trailing blank lines, and a single terminating newline
+pub fn normalize(input: &str) -> String {
+    let mut lines: Vec<&str> = input.lines().map(|l| if l.trim().is_empty() { "" } else { l.trim_end_matches('\r') }).collect();
+    while lines.last() == Some(&"") {
+        lines.pop();
+    }
+    if lines.is_empty() {
+        return String::new();
+    }
+    return lines.join("\n") + "\n";
+}
+
+// Conventional location of a day's input, relative to the working directory
+pub fn default_path(day: u32) -> PathBuf {
+    return PathBuf::from(format!("inputs/day{day:02}.txt"));
+}
+
+pub fn load(day: u32, arg: Option<&str>) -> io::Result<String> {
+    return InputSource::from_arg(day, arg).read();
+}
+
+// Loads the input named by the first command line argument, as used by the per-day binaries
+// Verbosity flags may appear anywhere and are applied first
+pub fn load_from_args(day: u32) -> io::Result<String> {
+    let args = log::take_flags(env::args().skip(1).collect());
+    return load(day, args.first().map(|a| a.as_str()));
+}
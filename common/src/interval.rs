@@ -0,0 +1,152 @@
+use std::ops::{Range, RangeInclusive};
+
+// A set of integers kept as sorted, disjoint ranges, so that huge ranges of numbers can be mapped and split without
+// listing them
+// Built from half-open (a..b) or closed (a..=b) ranges, and the ranges it gives back are half-open
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    // Half-open (start, end) pairs, none empty, and with gaps between them
+    ranges: Vec<(i64, i64)>
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        return IntervalSet { ranges: Vec::new() };
+    }
+
+    // Sorts the ranges and merges the ones that overlap or touch
+    fn from_pairs(mut pairs: Vec<(i64, i64)>) -> IntervalSet {
+        pairs.retain(|(start, end)| start < end);
+        pairs.sort();
+        let mut ranges: Vec<(i64, i64)> = Vec::with_capacity(pairs.len());
+        for (start, end) in pairs {
+            match ranges.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => ranges.push((start, end))
+            }
+        }
+        return IntervalSet { ranges: ranges };
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.ranges.is_empty();
+    }
+
+    // Number of integers in the set
+    pub fn len(&self) -> u64 {
+        return self.ranges.iter().map(|(start, end)| end.abs_diff(*start)).sum();
+    }
+
+    pub fn contains(&self, n: i64) -> bool {
+        return self.ranges.iter().any(|(start, end)| *start <= n && n < *end);
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        return self.ranges.first().map(|r| r.0);
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        return self.ranges.last().map(|r| r.1 - 1);
+    }
+
+    // The disjoint ranges that make up the set, in increasing order
+    pub fn ranges(&self) -> impl Iterator<Item = Range<i64>> + '_ {
+        return self.ranges.iter().map(|(start, end)| *start..*end);
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        return IntervalSet::from_pairs(self.ranges.iter().chain(other.ranges.iter()).copied().collect());
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (self.ranges[i], other.ranges[j]);
+            let overlap = (a.0.max(b.0), a.1.min(b.1));
+            if overlap.0 < overlap.1 {
+                ranges.push(overlap);
+            }
+            // Move past whichever range ends first, as it cannot overlap anything further on
+            if a.1 < b.1 {
+                i += 1;
+            }
+            else {
+                j += 1;
+            }
+        }
+        return IntervalSet { ranges: ranges };
+    }
+
+    // The integers of this set that are not in the other
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+        for (start, end) in self.ranges.iter().copied() {
+            let mut start = start;
+            // Skip the other's ranges that end before this one, then cut out the ones that overlap it
+            while j < other.ranges.len() && other.ranges[j].1 <= start {
+                j += 1;
+            }
+            let mut k = j;
+            while k < other.ranges.len() && other.ranges[k].0 < end {
+                if start < other.ranges[k].0 {
+                    ranges.push((start, other.ranges[k].0));
+                }
+                start = start.max(other.ranges[k].1);
+                k += 1;
+            }
+            if start < end {
+                ranges.push((start, end));
+            }
+        }
+        return IntervalSet { ranges: ranges };
+    }
+
+    // The integers below n and the integers from n up
+    pub fn split_at(&self, n: i64) -> (IntervalSet, IntervalSet) {
+        let mut below = Vec::new();
+        let mut above = Vec::new();
+        for (start, end) in self.ranges.iter().copied() {
+            if end <= n {
+                below.push((start, end));
+            }
+            else if start >= n {
+                above.push((start, end));
+            }
+            else {
+                below.push((start, n));
+                above.push((n, end));
+            }
+        }
+        return (IntervalSet { ranges: below }, IntervalSet { ranges: above });
+    }
+
+    // Adds offset to every integer in the set, or None if any of them would overflow
+    pub fn checked_shift(&self, offset: i64) -> Option<IntervalSet> {
+        let mut ranges = Vec::with_capacity(self.ranges.len());
+        for (start, end) in self.ranges.iter() {
+            ranges.push((start.checked_add(offset)?, end.checked_add(offset)?));
+        }
+        return Some(IntervalSet { ranges: ranges });
+    }
+}
+
+impl From<Range<i64>> for IntervalSet {
+    fn from(range: Range<i64>) -> IntervalSet {
+        return IntervalSet::from_pairs(vec![(range.start, range.end)]);
+    }
+}
+
+// Panics if the range ends at i64::MAX, which a half-open range cannot hold
+impl From<RangeInclusive<i64>> for IntervalSet {
+    fn from(range: RangeInclusive<i64>) -> IntervalSet {
+        return IntervalSet::from_pairs(vec![(*range.start(), range.end() + 1)]);
+    }
+}
+
+impl FromIterator<Range<i64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(ranges: I) -> IntervalSet {
+        return IntervalSet::from_pairs(ranges.into_iter().map(|r| (r.start, r.end)).collect());
+    }
+}
@@ -0,0 +1,22 @@
+// Shared building blocks for the daily solutions
+
+pub mod example;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod log;
+pub mod params;
+pub mod parse;
+pub mod search;
+
+mod answer;
+mod error;
+mod solution;
+
+pub use answer::Answer;
+pub use error::{Overflow, ParseError, SolveError};
+pub use geometry::{Direction, Point};
+pub use grid::Grid;
+pub use interval::IntervalSet;
+pub use solution::{run_main, Part, Solution, Solver};
@@ -0,0 +1,69 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+// How much diagnostic output the solvers write to stderr, leaving stdout for the answers alone
+// Nothing is shown by default, debug output (-v) covers summaries of intermediate results,
+// and trace output (--trace) covers every step and grid dump
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Quiet,
+    Debug,
+    Trace
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Quiet as u8);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    return match LEVEL.load(Ordering::Relaxed) {
+        0 => Level::Quiet,
+        1 => Level::Debug,
+        _ => Level::Trace
+    };
+}
+
+pub fn enabled(level: Level) -> bool {
+    return level <= self::level();
+}
+
+pub fn flag_level(arg: &str) -> Option<Level> {
+    return match arg {
+        "-v" | "--verbose" => Some(Level::Debug),
+        "--trace" => Some(Level::Trace),
+        _ => None
+    };
+}
+
+// Applies and removes any verbosity flags, returning the remaining arguments
+// The most verbose flag wins, so `-v --trace` means trace
+pub fn take_flags(args: Vec<String>) -> Vec<String> {
+    let mut remaining = Vec::new();
+    for arg in args {
+        match flag_level(&arg) {
+            Some(level) if level > self::level() => set_level(level),
+            Some(_) => (),
+            None => remaining.push(arg)
+        }
+    }
+    return remaining;
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Debug) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,66 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+// A puzzle constant that a day lets the user override, such as a number of steps or a test area bound
+pub struct Param {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub is_valid: fn(&str) -> bool
+}
+
+// Validator for parameters that must parse as the given type
+pub fn parses_as<T: FromStr>(value: &str) -> bool {
+    return value.parse::<T>().is_ok();
+}
+
+// Values given with --param name=value, which replace a day's defaults
+// Anything not given keeps the value from the puzzle text
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, String>
+}
+
+impl Params {
+    pub fn new() -> Params {
+        return Params::default();
+    }
+
+    // Takes a single `name=value` argument
+    pub fn set(&mut self, arg: &str) -> Result<(), String> {
+        let (name, value) = arg.split_once('=').ok_or_else(|| format!("Invalid parameter '{arg}', expected name=value"))?;
+        if name.is_empty() {
+            return Err(format!("Invalid parameter '{arg}', expected name=value"));
+        }
+        self.values.insert(String::from(name), String::from(value));
+        return Ok(());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.values.is_empty();
+    }
+
+    // Makes sure every value names one of the day's parameters and is valid for it, so that solving cannot fail later
+    pub fn check(&self, day: u32, accepted: &[Param]) -> Result<(), String> {
+        for (name, value) in &self.values {
+            let Some(param) = accepted.iter().find(|p| p.name == name) else {
+                if accepted.is_empty() {
+                    return Err(format!("Day {day:02} has no parameters"));
+                }
+                let names: Vec<&str> = accepted.iter().map(|p| p.name).collect();
+                return Err(format!("Day {day:02} has no parameter '{name}', expected one of {}", names.join(", ")));
+            };
+            if !(param.is_valid)(value) {
+                return Err(format!("Invalid value '{value}' for parameter '{name}' ({})", param.description));
+            }
+        }
+        return Ok(());
+    }
+
+    // The given value for a parameter, or its default when it was not given
+    // Panics if the value does not parse, which check() rules out for declared parameters
+    pub fn get_or<T: FromStr>(&self, name: &str, default: T) -> T {
+        return match self.values.get(name) {
+            Some(value) => value.parse().unwrap_or_else(|_| panic!("Invalid value '{value}' for parameter '{name}'")),
+            None => default
+        };
+    }
+}
@@ -0,0 +1,172 @@
+use std::{any, str::FromStr};
+
+use crate::{Grid, ParseError};
+
+// A piece of one input line that remembers where it came from, so that parse failures can point at it
+// Offsets are in bytes, which match columns for the ASCII puzzle inputs
+#[derive(Clone, Copy, Debug)]
+pub struct Span<'a> {
+    pub day: u32,
+    pub line: usize,
+    pub column: usize,
+    pub text: &'a str
+}
+
+impl<'a> Span<'a> {
+    pub fn error(&self, expected: &str) -> ParseError {
+        return ParseError {
+            day: self.day,
+            line: self.line,
+            column: self.column,
+            expected: String::from(expected),
+            found: String::from(self.text)
+        };
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        return self.text;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.text.is_empty();
+    }
+
+    pub fn len(&self) -> usize {
+        return self.text.len();
+    }
+
+    // Sub-span of text[start..end]
+    pub fn slice(&self, start: usize, end: usize) -> Span<'a> {
+        return Span { day: self.day, line: self.line, column: self.column + start, text: &self.text[start..end] };
+    }
+
+    // Wraps a piece borrowed from this span's text
+    fn sub(&self, piece: &'a str) -> Span<'a> {
+        let offset = piece.as_ptr() as usize - self.text.as_ptr() as usize;
+        return self.slice(offset, offset + piece.len());
+    }
+
+    pub fn trim(&self) -> Span<'a> {
+        return self.sub(self.text.trim());
+    }
+
+    pub fn find(&self, pat: &str) -> Result<usize, ParseError> {
+        return self.text.find(pat).ok_or_else(|| self.error(&format!("'{pat}'")));
+    }
+
+    pub fn split_once(&self, delim: &str) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        let idx = self.find(delim)?;
+        return Ok((self.slice(0, idx), self.slice(idx + delim.len(), self.text.len())));
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Span<'a>, ParseError> {
+        if !self.text.starts_with(prefix) {
+            return Err(self.error(&format!("'{prefix}'")));
+        }
+        return Ok(self.slice(prefix.len(), self.text.len()));
+    }
+
+    pub fn strip_suffix(&self, suffix: &str) -> Result<Span<'a>, ParseError> {
+        if !self.text.ends_with(suffix) {
+            return Err(self.error(&format!("'{suffix}'")));
+        }
+        return Ok(self.slice(0, self.text.len() - suffix.len()));
+    }
+
+    pub fn split(&self, delim: &str) -> Vec<Span<'a>> {
+        return self.text.split(delim).map(|piece| self.sub(piece)).collect();
+    }
+
+    pub fn split_whitespace(&self) -> Vec<Span<'a>> {
+        return self.text.split_ascii_whitespace().map(|piece| self.sub(piece)).collect();
+    }
+
+    // Parses the whole span, e.g. as a number
+    pub fn parse<T: FromStr>(&self) -> Result<T, ParseError> {
+        return self.text.parse().map_err(|_| self.error(any::type_name::<T>()));
+    }
+
+    // Fails at the first character that is_valid rejects
+    pub fn check_chars(&self, is_valid: impl Fn(char) -> bool, expected: &str) -> Result<(), ParseError> {
+        if let Some((idx, c)) = self.text.char_indices().find(|(_, c)| !is_valid(*c)) {
+            return Err(self.slice(idx, idx + c.len_utf8()).error(expected));
+        }
+        return Ok(());
+    }
+
+    // Parses a single character from the given set, e.g. a direction letter
+    pub fn char_of(&self, allowed: &str) -> Result<char, ParseError> {
+        let mut chars = self.text.chars();
+        return match (chars.next(), chars.next()) {
+            (Some(c), None) if allowed.contains(c) => Ok(c),
+            _ => Err(self.error(&format!("one of '{allowed}'")))
+        };
+    }
+}
+
+// Splits the input into numbered lines
+pub fn lines(day: u32, input: &str) -> Vec<Span<'_>> {
+    return input.lines().enumerate().map(|(i, l)| Span { day: day, line: i + 1, column: 1, text: l }).collect();
+}
+
+// Splits the input into blocks of lines separated by blank lines
+pub fn sections(day: u32, input: &str) -> Vec<Vec<Span<'_>>> {
+    let mut sections = Vec::new();
+    let mut section = Vec::new();
+    for ln in lines(day, input) {
+        if ln.is_empty() {
+            if !section.is_empty() {
+                sections.push(section);
+                section = Vec::new();
+            }
+        }
+        else {
+            section.push(ln);
+        }
+    }
+    if !section.is_empty() {
+        sections.push(section);
+    }
+    return sections;
+}
+
+// Error for input that stops before something the parser still needs
+pub fn end_of_input(day: u32, input: &str, expected: &str) -> ParseError {
+    return ParseError { day: day, line: input.lines().count() + 1, column: 1, expected: String::from(expected), found: String::new() };
+}
+
+// Reads a non-empty rectangular grid of characters, each of which must be one of `allowed`
+pub fn char_grid(day: u32, input: &str, allowed: &str) -> Result<Grid<char>, ParseError> {
+    return grid(day, input, &format!("one of '{allowed}'"), |c| if allowed.contains(c) { Some(c) } else { None });
+}
+
+// Same as char_grid, for a block of lines that has already been split out of the input
+pub fn char_grid_lines(lines: &[Span], allowed: &str) -> Result<Grid<char>, ParseError> {
+    return grid_lines(lines, &format!("one of '{allowed}'"), |c| if allowed.contains(c) { Some(c) } else { None });
+}
+
+// Reads a non-empty rectangular grid, turning each character into a cell
+// Characters that `cell` gives None for are rejected as not being the `expected` kind of cell
+pub fn grid<T>(day: u32, input: &str, expected: &str, cell: impl Fn(char) -> Option<T>) -> Result<Grid<T>, ParseError> {
+    let lines = lines(day, input);
+    if lines.is_empty() {
+        return Err(end_of_input(day, input, "a grid row"));
+    }
+    return grid_lines(&lines, expected, cell);
+}
+
+// Same as grid, for a block of lines that has already been split out of the input
+pub fn grid_lines<T>(lines: &[Span], expected: &str, cell: impl Fn(char) -> Option<T>) -> Result<Grid<T>, ParseError> {
+    let mut rows = Vec::new();
+    for ln in lines {
+        if ln.len() != lines[0].len() {
+            return Err(ln.error(&format!("a row of width {}", lines[0].len())));
+        }
+        let mut row = Vec::new();
+        for (idx, c) in ln.as_str().char_indices() {
+            row.push(cell(c).ok_or_else(|| ln.slice(idx, idx + c.len_utf8()).error(expected))?);
+        }
+        rows.push(row);
+    }
+    return Ok(Grid::from_rows(rows));
+}
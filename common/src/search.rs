@@ -0,0 +1,165 @@
+use std::{cmp::Ordering, collections::{BinaryHeap, HashMap, VecDeque}, hash::Hash, ops::Add};
+
+// Shortest distances from the start to the states a search reached, and the state each one was last reached from,
+// so that the paths can be followed back
+// A search that stops at a goal has only settled the states closer than the goal, so others may have longer
+// distances than their shortest
+pub struct Paths<S, C> {
+    start: S,
+    distances: HashMap<S, C>,
+    predecessors: HashMap<S, S>,
+    goal: Option<S>
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Paths<S, C> {
+    fn new(start: S, zero: C) -> Paths<S, C> {
+        return Paths { start: start.clone(), distances: HashMap::from([(start, zero)]), predecessors: HashMap::new(), goal: None };
+    }
+
+    pub fn start(&self) -> &S {
+        return &self.start;
+    }
+
+    // The first goal state the search settled, if it was given goals and reached one
+    pub fn goal(&self) -> Option<&S> {
+        return self.goal.as_ref();
+    }
+
+    pub fn distance(&self, state: &S) -> Option<C> {
+        return self.distances.get(state).copied();
+    }
+
+    // Every state reached, in no particular order
+    pub fn distances(&self) -> impl Iterator<Item = (&S, C)> {
+        return self.distances.iter().map(|(state, distance)| (state, *distance));
+    }
+
+    pub fn predecessor(&self, state: &S) -> Option<&S> {
+        return self.predecessors.get(state);
+    }
+
+    // The states from the start to the given state, both included
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return None;
+        }
+        let mut path = vec![state.clone()];
+        while let Some(prev) = self.predecessors.get(path.last().unwrap()) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        return Some(path);
+    }
+}
+
+// A state waiting in the heap, ordered so that the one with the lowest estimated total cost comes out first
+struct QueueEntry<S, C> {
+    estimate: C,
+    cost: C,
+    state: S
+}
+
+impl<S, C: Ord> Ord for QueueEntry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return other.estimate.cmp(&self.estimate);
+    }
+}
+
+impl<S, C: Ord> PartialOrd for QueueEntry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl<S, C: Ord> PartialEq for QueueEntry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        return self.estimate == other.estimate;
+    }
+}
+
+impl<S, C: Ord> Eq for QueueEntry<S, C> {}
+
+// Dijkstra's algorithm from the start over the (state, cost) pairs that neighbors gives for each state, stopping at
+// the first goal state it settles, or once every reachable state is settled if no state is a goal
+// Costs must not be negative, and start from C::default()
+pub fn dijkstra<S, C, I>(start: S, neighbors: impl FnMut(&S) -> I, is_goal: impl FnMut(&S) -> bool) -> Paths<S, C>
+    where S: Clone + Eq + Hash, C: Copy + Ord + Add<Output = C> + Default, I: IntoIterator<Item = (S, C)> {
+    return astar(start, neighbors, |_| C::default(), is_goal);
+}
+
+// A* search, which is Dijkstra's algorithm settling first the states with the lowest cost so far plus the
+// heuristic's estimate of the cost left to a goal
+// The heuristic must never overestimate, or the distance found to the goal may not be the shortest
+pub fn astar<S, C, I>(start: S, mut neighbors: impl FnMut(&S) -> I, mut heuristic: impl FnMut(&S) -> C,
+                      mut is_goal: impl FnMut(&S) -> bool) -> Paths<S, C>
+    where S: Clone + Eq + Hash, C: Copy + Ord + Add<Output = C> + Default, I: IntoIterator<Item = (S, C)> {
+    let mut paths = Paths::new(start.clone(), C::default());
+    let mut heap = BinaryHeap::new();
+    heap.push(QueueEntry { estimate: heuristic(&start), cost: C::default(), state: start });
+    while let Some(QueueEntry { cost, state, .. }) = heap.pop() {
+        // Skip states that were reached more cheaply after this entry was queued
+        if paths.distances[&state] < cost {
+            continue;
+        }
+        if is_goal(&state) {
+            paths.goal = Some(state);
+            break;
+        }
+        for (next, step_cost) in neighbors(&state) {
+            let next_cost = cost + step_cost;
+            if paths.distances.get(&next).is_none_or(|d| next_cost < *d) {
+                paths.distances.insert(next.clone(), next_cost);
+                paths.predecessors.insert(next.clone(), state.clone());
+                heap.push(QueueEntry { estimate: next_cost + heuristic(&next), cost: next_cost, state: next });
+            }
+        }
+    }
+    return paths;
+}
+
+// Breadth-first search from the start, where every step costs 1, over every state reachable from it
+pub fn bfs<S, I>(start: S, mut neighbors: impl FnMut(&S) -> I) -> Paths<S, usize>
+    where S: Clone + Eq + Hash, I: IntoIterator<Item = S> {
+    let mut paths = Paths::new(start.clone(), 0);
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        let next_distance = paths.distances[&state] + 1;
+        for next in neighbors(&state) {
+            if !paths.distances.contains_key(&next) {
+                paths.distances.insert(next.clone(), next_distance);
+                paths.predecessors.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    return paths;
+}
+
+// Breadth-first search where steps cost 0 or 1, which queues free steps at the front so that states still come out
+// in order of distance
+// Panics on a step that costs more than 1
+pub fn zero_one_bfs<S, I>(start: S, mut neighbors: impl FnMut(&S) -> I) -> Paths<S, usize>
+    where S: Clone + Eq + Hash, I: IntoIterator<Item = (S, usize)> {
+    let mut paths = Paths::new(start.clone(), 0);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, distance)) = queue.pop_front() {
+        if paths.distances[&state] < distance {
+            continue;
+        }
+        for (next, step_cost) in neighbors(&state) {
+            assert!(step_cost <= 1, "Steps of a 0-1 BFS must cost 0 or 1, not {step_cost}");
+            let next_distance = distance + step_cost;
+            if paths.distances.get(&next).is_none_or(|d| next_distance < *d) {
+                paths.distances.insert(next.clone(), next_distance);
+                paths.predecessors.insert(next.clone(), state.clone());
+                if step_cost == 0 {
+                    queue.push_front((next, next_distance));
+                }
+                else {
+                    queue.push_back((next, next_distance));
+                }
+            }
+        }
+    }
+    return paths;
+}
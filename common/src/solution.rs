@@ -0,0 +1,100 @@
+use std::{any::Any, fmt, process};
+
+use crate::{example::Example, input, params::{Param, Params}, Answer, ParseError, SolveError};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2")
+        }
+    }
+}
+
+// Implemented by each day
+// The puzzle input is parsed once into `Input`, which is then shared by both parts
+// Days with constants that can be overridden list them in PARAMS and read them in part1_with/part2_with,
+// with part1/part2 using the values from the puzzle text
+// Parts fail with SolveError when an answer does not fit the type it is computed in, or the input cannot be answered
+// EXAMPLES embeds the day's fixtures with the example! macro, for `aoc run --example`
+pub trait Solution {
+    const DAY: u32;
+    const PARAMS: &'static [Param] = &[];
+    const EXAMPLES: &'static [Example] = &[];
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError>;
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError>;
+
+    fn part1_with(input: &Self::Input, _params: &Params) -> Result<Answer, SolveError> {
+        return Self::part1(input);
+    }
+
+    fn part2_with(input: &Self::Input, _params: &Params) -> Result<Answer, SolveError> {
+        return Self::part2(input);
+    }
+}
+
+// Object-safe view of a Solution, so that days with different input types can be driven generically
+pub trait Solver: Sync {
+    fn day(&self) -> u32;
+    fn params(&self) -> &'static [Param];
+    fn examples(&self) -> &'static [Example];
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+    // Params must already have been checked against params()
+    fn solve(&self, parsed: &dyn Any, part: Part, params: &Params) -> Result<Answer, SolveError>;
+}
+
+impl<S> Solver for S where S: Solution + Sync, S::Input: 'static {
+    fn day(&self) -> u32 {
+        return S::DAY;
+    }
+
+    fn params(&self) -> &'static [Param] {
+        return S::PARAMS;
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        return S::EXAMPLES;
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        let parsed = S::parse(input)?;
+        return Ok(Box::new(parsed));
+    }
+
+    fn solve(&self, parsed: &dyn Any, part: Part, params: &Params) -> Result<Answer, SolveError> {
+        let input = parsed.downcast_ref::<S::Input>().expect("Parsed input does not belong to this day");
+        return match part {
+            Part::One => S::part1_with(input, params),
+            Part::Two => S::part2_with(input, params)
+        };
+    }
+}
+
+// The whole of a per-day binary: reads the input named on the command line, solves both parts and prints them,
+// exiting with status 1 and the reason on stderr if any step fails
+pub fn run_main<S: Solution>() {
+    let input_str = input::load_from_args(S::DAY).unwrap_or_else(|e| fail(&format!("could not read input: {e}")));
+    let input = S::parse(&input_str).unwrap_or_else(|e| fail(&format!("invalid input: {e}")));
+    let part1 = S::part1(&input).unwrap_or_else(|e| fail(&e.to_string()));
+    let part2 = S::part2(&input).unwrap_or_else(|e| fail(&e.to_string()));
+    println!("Part 1: {part1}");
+    println!("Part 2: {part2}");
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("Error: {message}");
+    process::exit(1);
+}
@@ -0,0 +1,119 @@
+//! Day 1: Trebuchet?!
+//!
+//! Recovers calibration values from lines of text: the first and last digit of each line form a two-digit number
+
+#![warn(missing_docs)]
+
+use common::{example, example::Example, parse, Answer, ParseError, Solution, SolveError};
+use regex::Regex;
+
+/// Solution for day 1, for use with the `aoc` runner
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    const EXAMPLES: &'static [Example] = &[
+        example!("example-part1"),
+        example!("example-part2")
+    ];
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(lines).into());
+    }
+
+    fn part2(lines: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(lines).into());
+    }
+}
+
+/// Reads the calibration lines, which may only contain letters and digits
+/// Every line must hold a digit, or a digit spelled out as a word, for part 2 to find its calibration value
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    let digit_re = Regex::new(r"zero|one|two|three|four|five|six|seven|eight|nine|[0-9]").unwrap();
+    let mut lines = Vec::new();
+    for ln in parse::lines(Day01::DAY, input) {
+        ln.check_chars(|c| c.is_ascii_alphanumeric(), "a letter or digit")?;
+        if !digit_re.is_match(ln.as_str()) {
+            return Err(ln.error("a digit or a digit spelled out as a word"));
+        }
+        lines.push(String::from(ln.as_str()));
+    }
+    return Ok(lines);
+}
+
+/// Sums the calibration values, using only the numeric digits in each line
+pub fn part1(lines: &Vec<String>) -> u64 {
+    let mut subtotal = 0;
+    for line in lines {
+        let mut first_digit = 0;
+        let mut last_digit = 0;
+        for c in line.chars() {
+            if c.is_ascii_digit() {
+                first_digit = c.to_digit(10).unwrap();
+                break;
+            }
+        }
+        for c in line.chars().rev() {
+            if c.is_ascii_digit() {
+                last_digit = c.to_digit(10).unwrap();
+                break;
+            }
+        }
+        
+        subtotal += u64::from(10 * first_digit + last_digit);
+    }
+    return subtotal;
+}
+
+/// Sums the calibration values, also counting digits spelled out as words, such as `one` and `two`
+/// Overlapping words count for both ends, so `twone` gives 21
+pub fn part2(lines: &Vec<String>) -> u64 {
+    let first_re = Regex::new(r"zero|one|two|three|four|five|six|seven|eight|nine|ten|0|1|2|3|4|5|6|7|8|9").unwrap();
+    let last_re = Regex::new(r"orez|eno|owt|eerht|ruof|evif|xis|neves|thgie|enin|net|0|1|2|3|4|5|6|7|8|9").unwrap();
+
+    let mut subtotal = 0;
+    for line in lines {
+        if line.len() == 0 {
+            break;
+        }
+        let reversed_line = line.chars().rev().collect::<String>();
+
+        let first_number = first_re.find(line).unwrap().as_str();
+        let last_number = last_re.find(&reversed_line).unwrap().as_str();
+        
+        let first_digit = match first_number {
+            "0" | "zero" => 0,
+            "1" | "one" => 1,
+            "2" | "two" => 2,
+            "3" | "three" => 3,
+            "4" | "four" => 4,
+            "5" | "five" => 5,
+            "6" | "six" => 6,
+            "7" | "seven" => 7,
+            "8" | "eight" => 8,
+            "9" | "nine" => 9,
+            _ => 0
+        };
+        let last_digit = match last_number {
+            "0" | "orez" => 0,
+            "1" | "eno" => 1,
+            "2" | "owt" => 2,
+            "3" | "eerht" => 3,
+            "4" | "ruof" => 4,
+            "5" | "evif" => 5,
+            "6" | "xis" => 6,
+            "7" | "neves" => 7,
+            "8" | "thgie" => 8,
+            "9" | "enin" => 9,
+            _ => 0
+        };
+        //println!("{first_digit}{last_digit}");
+        subtotal += 10 * first_digit + last_digit;
+    }
+    return subtotal;
+}
\ No newline at end of file
@@ -0,0 +1,136 @@
+//! Day 2: Cube Conundrum
+//!
+//! Works out which games of drawing colored cubes from a bag are possible, and the fewest cubes each game needs
+
+#![warn(missing_docs)]
+
+use common::{example, example::Example, params::{self, Param, Params}, parse, Answer, Overflow, ParseError, Solution, SolveError};
+
+/// Solution for day 2, for use with the `aoc` runner
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    const PARAMS: &'static [Param] = &[
+        Param { name: "red", description: "red cubes in the bag for part 1, default 12", is_valid: params::parses_as::<u32> },
+        Param { name: "green", description: "green cubes in the bag for part 1, default 13", is_valid: params::parses_as::<u32> },
+        Param { name: "blue", description: "blue cubes in the bag for part 1, default 14", is_valid: params::parses_as::<u32> }
+    ];
+    const EXAMPLES: &'static [Example] = &[example!("example")];
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(games: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(games).into());
+    }
+
+    fn part2(games: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(games)?.into());
+    }
+
+    fn part1_with(games: &Self::Input, params: &Params) -> Result<Answer, SolveError> {
+        return Ok(sum_possible_games(games, params.get_or("red", NUM_RED), params.get_or("green", NUM_GREEN), params.get_or("blue", NUM_BLUE)).into());
+    }
+}
+
+/// Red cubes in the bag for part 1
+pub const NUM_RED: u32 = 12;
+/// Green cubes in the bag for part 1
+pub const NUM_GREEN: u32 = 13;
+/// Blue cubes in the bag for part 1
+pub const NUM_BLUE: u32 = 14;
+
+/// One game, made of the handfuls of cubes revealed from the bag
+pub struct Game {
+    /// Game number from the `Game N:` prefix
+    pub id: u32,
+    /// Each handful of cubes, in order
+    pub pulls: Vec<Pull>
+}
+
+/// Cubes of each color revealed in one handful, with colors that were not mentioned left at 0
+#[derive(Default)]
+pub struct Pull {
+    /// Number of red cubes
+    pub red: u32,
+    /// Number of green cubes
+    pub green: u32,
+    /// Number of blue cubes
+    pub blue: u32
+}
+
+/// Reads one game per line, such as `Game 1: 3 blue, 4 red; 1 red, 2 green`
+pub fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    let mut games = Vec::new();
+    for line in parse::lines(Day02::DAY, input) {
+        let (game_str, pulls_str) = line.split_once(": ")?;
+        let game_num = game_str.strip_prefix("Game ")?.parse::<u32>()?;
+        let mut pulls = Vec::new();
+        for p in pulls_str.split("; ") {
+            let mut pull = Pull::default();
+            let ball_count_entries = p.split(", ");
+            for entry in ball_count_entries {
+                let (ball_num_str, ball_color) = entry.split_once(" ")?;
+                let ball_num = ball_num_str.parse::<u32>()?;
+                match ball_color.as_str() {
+                    "red" => pull.red = ball_num,
+                    "green" => pull.green = ball_num,
+                    "blue" => pull.blue = ball_num,
+                    _ => return Err(ball_color.error("'red', 'green' or 'blue'"))
+                }
+            }
+            pulls.push(pull);
+        }
+        games.push(Game { id: game_num, pulls: pulls });
+    }
+    return Ok(games);
+}
+
+/// Sums the IDs of the games that are possible with 12 red, 13 green and 14 blue cubes in the bag
+pub fn part1(games: &Vec<Game>) -> u64 {
+    return sum_possible_games(games, NUM_RED, NUM_GREEN, NUM_BLUE);
+}
+
+/// Sums the IDs of the games that could have been played with the given numbers of cubes in the bag
+pub fn sum_possible_games(games: &Vec<Game>, num_red: u32, num_green: u32, num_blue: u32) -> u64 {
+    let mut subtotal = 0;
+    'outer_loop: for game in games {
+        for p in &game.pulls {
+            if (p.red > num_red) || (p.green > num_green) || (p.blue > num_blue) {
+                continue 'outer_loop;
+            }
+        }
+        subtotal += u64::from(game.id);
+    }
+    return subtotal;
+}
+
+/// Sums the power of the minimum set of cubes for each game, which is the product of the largest count of each color
+pub fn part2(games: &Vec<Game>) -> Result<u64, Overflow> {
+    let overflow = || Overflow::new(Day02::DAY, "sum of powers");
+    let mut subtotal: u64 = 0;
+    for game in games {
+        let mut max_red = 0;
+        let mut max_blue = 0;
+        let mut max_green = 0;
+
+        for p in &game.pulls {
+            if p.red > max_red {
+                max_red = p.red;
+            }
+            if p.green > max_green {
+                max_green = p.green;
+            }
+            if p.blue > max_blue {
+                max_blue = p.blue;
+            }
+        }
+
+        let power = u64::from(max_red).checked_mul(u64::from(max_green)).and_then(|n| n.checked_mul(u64::from(max_blue))).ok_or_else(overflow)?;
+        subtotal = subtotal.checked_add(power).ok_or_else(overflow)?;
+    }
+    return Ok(subtotal);
+}
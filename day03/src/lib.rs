@@ -0,0 +1,121 @@
+//! Day 3: Gear Ratios
+//!
+//! Finds the part numbers in an engine schematic, which are the numbers next to a symbol
+
+#![warn(missing_docs)]
+
+use std::collections::{HashMap, HashSet};
+use common::{example, example::Example, parse, Answer, Grid, Overflow, ParseError, Solution, SolveError};
+
+/// Solution for day 3, for use with the `aoc` runner
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    const EXAMPLES: &'static [Example] = &[example!("example")];
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(grid)?.into());
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(grid)?.into());
+    }
+}
+
+/// Reads the schematic into a character grid
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    return parse::grid(Day03::DAY, input, "a digit, '.' or a symbol", |c| if c.is_ascii_graphic() { Some(c) } else { None });
+}
+
+/// Sums every number that is adjacent to a symbol, including diagonally
+pub fn part1(grid: &Grid<char>) -> Result<u64, Overflow> {
+    let overflow = || Overflow::new(Day03::DAY, "sum of part numbers");
+
+    // Add up part numbers
+    let mut subtotal: u64 = 0;
+    for i in 0..grid.height() {
+        let mut number_value: u64 = 0;
+        let mut is_touching_symbol = false;
+        for j in 0..(grid.width() + 1) { // Goes one past the end of the row so numbers finish building
+            let ch = grid.get((i, j)).copied().unwrap_or('.');
+            if ch.is_ascii_digit() { // Building number
+                number_value = number_value.checked_mul(10).and_then(|n| n.checked_add(u64::from(ch.to_digit(10).unwrap()))).ok_or_else(overflow)?;
+
+                for neighbor in grid.neighbors8((i, j)) {
+                    if !grid[neighbor].is_ascii_digit() && grid[neighbor] != '.' {
+                        is_touching_symbol = true;
+                    }
+                }
+            }
+            else { // Finish building number (if any)
+                if number_value > 0 {
+                    // println!("{number_value}, {is_touching_symbol}");
+                }
+                if is_touching_symbol {
+                    subtotal = subtotal.checked_add(number_value).ok_or_else(overflow)?;
+                }
+                number_value = 0;
+                is_touching_symbol = false;
+            }
+        }
+    }
+    return Ok(subtotal);
+}
+
+/// Sums the gear ratios, which are the products of the two numbers next to each `'*'` that has exactly two
+pub fn part2(grid: &Grid<char>) -> Result<u64, Overflow> {
+    let overflow = || Overflow::new(Day03::DAY, "sum of gear ratios");
+
+    // Find all numbers and the gears they are adjacent to
+    let mut gears: HashMap<(usize, usize), Vec<u64>> = HashMap::new();
+    for i in 0..grid.height() {
+        let mut number_value: u64 = 0;
+        let mut number_start = None;
+        let mut adj_gears: HashSet<(usize, usize)> = HashSet::new();
+        for j in 0..(grid.width() + 1) { // Goes one past the end of the row so numbers finish building
+            let ch = grid.get((i, j)).copied().unwrap_or('.');
+            if ch.is_ascii_digit() { // Building number
+                if number_start.is_none() {
+                    number_start = Some((i, j));
+                }
+
+                number_value = number_value.checked_mul(10).and_then(|n| n.checked_add(u64::from(ch.to_digit(10).unwrap()))).ok_or_else(overflow)?;
+
+                for neighbor in grid.neighbors8((i, j)) {
+                    if grid[neighbor] == '*' {
+                        adj_gears.insert(neighbor);
+                    }
+                }
+            }
+            else { // Finish building number (if any)
+                if number_start.is_some() {
+                    //println!("{}, {:?}, {:?}", number_value, number_start, adj_gears);
+                    for g in adj_gears {
+                        let gear_num_list = gears.entry(g).or_default();
+                        gear_num_list.push(number_value);
+                    }
+                }
+                number_value = 0;
+                number_start = None;
+                adj_gears = HashSet::new();
+            }
+        }
+    }
+    //println!("{:?}", gears);
+
+    // Find gear number for each gear adjacent to exactly two numbers
+    let mut subtotal: u64 = 0;
+    for adj_nums in gears.values() {
+        if adj_nums.len() == 2 {
+            let gear_ratio = adj_nums[0].checked_mul(adj_nums[1]).ok_or_else(overflow)?;
+            subtotal = subtotal.checked_add(gear_ratio).ok_or_else(overflow)?;
+        }
+    }
+    return Ok(subtotal);
+}
\ No newline at end of file
@@ -0,0 +1,114 @@
+//! Day 4: Scratchcards
+//!
+//! Scores scratchcards by how many of their numbers are winning numbers
+
+#![warn(missing_docs)]
+
+use std::collections::HashSet;
+use std::cmp;
+use common::{example, example::Example, parse, Answer, Overflow, ParseError, Solution, SolveError};
+
+/// Solution for day 4, for use with the `aoc` runner
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    const EXAMPLES: &'static [Example] = &[example!("example")];
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(cards: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(cards)?.into());
+    }
+
+    fn part2(cards: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(cards)?.into());
+    }
+}
+
+/// One scratchcard
+pub struct Card {
+    /// Winning numbers, listed before the `|`
+    pub winning_nums: HashSet<u32>,
+    /// Numbers on the card, listed after the `|`
+    pub card_nums: Vec<u32>
+}
+
+/// Reads one card per line, such as `Card 1: 41 48 83 | 83 86  6`
+pub fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+    let mut cards = Vec::new();
+    for line in parse::lines(Day04::DAY, input) {
+        // Partition line into winning nums and card nums section
+        let (_, nums_str) = line.split_once(":")?;
+        let (winning_nums_substr, card_nums_substr) = nums_str.split_once("|")?;
+
+        // Get winning nums as HashSet, card nums as Vec
+        let mut winning_nums = HashSet::new();
+        for n in winning_nums_substr.split_whitespace() {
+            winning_nums.insert(n.parse::<u32>()?);
+        }
+        let mut card_nums = Vec::new();
+        for n in card_nums_substr.split_whitespace() {
+            card_nums.push(n.parse::<u32>()?);
+        }
+        cards.push(Card { winning_nums: winning_nums, card_nums: card_nums });
+    }
+    return Ok(cards);
+}
+
+/// Sums the points of each card, which double for every match after the first
+pub fn part1(cards: &Vec<Card>) -> Result<u64, Overflow> {
+    let overflow = || Overflow::new(Day04::DAY, "total points");
+    let mut subtotal: u64 = 0;
+    for card in cards {
+        // Calculate line score based on number of card nums which are winning nums
+        let mut line_score: u64 = 0;
+        for c in &card.card_nums {
+            if card.winning_nums.contains(c) {
+                if line_score == 0 {
+                    line_score = 1;
+                }
+                else {
+                    line_score = line_score.checked_mul(2).ok_or_else(overflow)?;
+                }
+            }
+        }
+        subtotal = subtotal.checked_add(line_score).ok_or_else(overflow)?;
+    }
+    return Ok(subtotal);
+}
+
+/// Counts the total number of cards after each card wins copies of as many following cards as it has matches
+pub fn part2(cards: &Vec<Card>) -> Result<u64, Overflow> {
+    let overflow = || Overflow::new(Day04::DAY, "number of cards");
+    // Calculate number of wins for each card
+    let mut num_winning = Vec::new();
+    for card in cards {
+        // Calculate number of card numbers which are winning for the given line
+        let mut line_score = 0;
+        for c in &card.card_nums {
+            if card.winning_nums.contains(c) {
+                line_score += 1;
+            }
+        }
+        num_winning.push(line_score);
+    }
+    //println!("{:?}", num_winning);
+
+    // Calculate total number of copies
+    let num_cards = num_winning.len();
+    let mut num_copies: Vec<u64> = vec![1; num_cards];
+    let mut subtotal: u64 = 0;
+    for i in 0..num_cards {
+        subtotal = subtotal.checked_add(num_copies[i]).ok_or_else(overflow)?;
+        for j in (i + 1)..cmp::min(i + num_winning[i] + 1, num_cards) {
+            num_copies[j] = num_copies[j].checked_add(num_copies[i]).ok_or_else(overflow)?;
+        }
+    }
+    //println!("{:?}", num_copies);
+
+    return Ok(subtotal);
+}
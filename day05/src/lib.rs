@@ -0,0 +1,129 @@
+//! Day 5: If You Give A Seed A Fertilizer
+//!
+//! Follows seed numbers through a chain of almanac maps, from seed to soil and on to location
+
+#![warn(missing_docs)]
+
+use common::{debug, example, example::Example, parse, Answer, IntervalSet, Overflow, ParseError, Solution, SolveError};
+
+/// Solution for day 5, for use with the `aoc` runner
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    const EXAMPLES: &'static [Example] = &[example!("example")];
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(almanac: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(almanac)?.into());
+    }
+
+    fn part2(almanac: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(almanac)?.into());
+    }
+}
+
+/// The seeds to plant and the maps between categories, in order
+pub struct Almanac {
+    /// Numbers from the `seeds:` line
+    pub seeds: Vec<i64>,
+    /// Each map's ranges as `(source_start, range_size, offset)`, where `offset` is added to numbers inside the range
+    pub maps: Vec<Vec<(i64, i64, i64)>>
+}
+
+/// Reads the `seeds:` line followed by one section per map
+pub fn parse(file_str: &str) -> Result<Almanac, ParseError> {
+    let sections = parse::sections(Day05::DAY, file_str);
+    if sections.is_empty() {
+        return Err(parse::end_of_input(Day05::DAY, file_str, "'seeds:'"));
+    }
+
+    // Create map ranges
+    let mut maps = Vec::new();
+    for map_lines in &sections[1..] {
+        map_lines[0].strip_suffix("map:")?;
+        let mut map_ranges = Vec::new();
+        for line in &map_lines[1..] {
+            let mut line_nums = Vec::new();
+            for n in line.split(" ") {
+                line_nums.push(n.parse::<i64>()?);
+            }
+            if line_nums.len() != 3 {
+                return Err(line.error("3 numbers"));
+            }
+            // Output in src_start, range_size, offset format
+            let Some(offset) = line_nums[0].checked_sub(line_nums[1]) else {
+                return Err(line.error("a destination and source start less than 2^63 apart"));
+            };
+            map_ranges.push((line_nums[1], line_nums[2], offset));
+        }
+        maps.push(map_ranges);
+    }
+
+    // Get seed numbers
+    let seed_line = sections[0][0];
+    let mut seed_nums = Vec::new();
+    for n in seed_line.strip_prefix("seeds:")?.split_whitespace() {
+        seed_nums.push(n.parse::<i64>()?);
+    }
+
+    return Ok(Almanac { seeds: seed_nums, maps: maps });
+}
+
+/// Finds the lowest location number for any of the listed seeds
+/// Fails if the seeds line lists no seeds
+pub fn part1(almanac: &Almanac) -> Result<i64, SolveError> {
+    let range_overflow = || Overflow::new(Day05::DAY, "end of a map range");
+    let number_overflow = || Overflow::new(Day05::DAY, "mapped number");
+
+    // Determine end locations for seed numbers
+    let mut min_location = None;
+    for mut n in almanac.seeds.iter().copied() {
+        for map_ranges in &almanac.maps {
+            for (src_start, range_size, offset) in map_ranges {
+                if n >= *src_start && n < src_start.checked_add(*range_size).ok_or_else(range_overflow)? {
+                    n = n.checked_add(*offset).ok_or_else(number_overflow)?;
+                    break;
+                }
+            }
+        }
+        if min_location.is_none_or(|min| n < min) {
+            min_location = Some(n);
+        }
+        //println!("{n}");
+    }
+
+    return min_location.ok_or_else(|| SolveError::unsolvable(Day05::DAY, "there are no seeds to plant"));
+}
+
+/// Finds the lowest location number when the seeds line lists pairs of range starts and lengths
+/// The seed ranges are mapped through each map as whole sets of numbers, so every seed is accounted for
+/// Fails if the seed ranges hold no seeds
+pub fn part2(almanac: &Almanac) -> Result<i64, SolveError> {
+    let seed_nums = &almanac.seeds;
+    let mut seed_ranges = Vec::new();
+    for i in 0..(seed_nums.len() / 2) {
+        let seed_end = seed_nums[2 * i].checked_add(seed_nums[2 * i + 1]).ok_or_else(|| Overflow::new(Day05::DAY, "end of a seed range"))?;
+        seed_ranges.push(seed_nums[2 * i]..seed_end);
+    }
+    let mut numbers: IntervalSet = seed_ranges.into_iter().collect();
+    for map_ranges in &almanac.maps {
+        // Numbers take the offset of the first range they fall in, and keep their value if they fall in none
+        let mut mapped = IntervalSet::new();
+        for (src_start, range_size, offset) in map_ranges {
+            let src_end = src_start.checked_add(*range_size).ok_or_else(|| Overflow::new(Day05::DAY, "end of a map range"))?;
+            let src_range = IntervalSet::from(*src_start..src_end);
+            let shifted = numbers.intersection(&src_range).checked_shift(*offset).ok_or_else(|| Overflow::new(Day05::DAY, "mapped number"))?;
+            mapped = mapped.union(&shifted);
+            numbers = numbers.difference(&src_range);
+        }
+        numbers = mapped.union(&numbers);
+        debug!("{} numbers in {} ranges", numbers.len(), numbers.ranges().count());
+    }
+
+    return numbers.min().ok_or_else(|| SolveError::unsolvable(Day05::DAY, "the seed ranges hold no seeds"));
+}
\ No newline at end of file
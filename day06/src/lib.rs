@@ -0,0 +1,111 @@
+//! Day 6: Wait For It
+//!
+//! Counts the ways to win boat races by holding the button for longer, trading time for speed
+
+#![warn(missing_docs)]
+
+use std::num::IntErrorKind;
+use common::{debug, example, example::Example, parse, Answer, Overflow, ParseError, Solution, SolveError};
+
+/// Solution for day 6, for use with the `aoc` runner
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    const EXAMPLES: &'static [Example] = &[example!("example")];
+    type Input = Races;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(races: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(races)?.into());
+    }
+
+    fn part2(races: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(races)?.into());
+    }
+}
+
+/// Race times and record distances, read column by column from the sheet
+pub struct Races {
+    /// Duration of each race in milliseconds
+    pub times: Vec<i64>,
+    /// Record distance of each race in millimeters
+    pub distances: Vec<i64>
+}
+
+/// Reads the `Time:` and `Distance:` lines, which must list the same number of races
+pub fn parse(file_str: &str) -> Result<Races, ParseError> {
+    let lines = parse::lines(Day06::DAY, file_str);
+    if lines.len() < 2 {
+        return Err(parse::end_of_input(Day06::DAY, file_str, "'Time:' and 'Distance:' lines"));
+    }
+    let mut times = Vec::new();
+    for n in lines[0].strip_prefix("Time:")?.split_whitespace() {
+        times.push(n.parse::<i64>()?);
+    }
+    let mut distances = Vec::new();
+    for n in lines[1].strip_prefix("Distance:")?.split_whitespace() {
+        distances.push(n.parse::<i64>()?);
+    }
+    if distances.len() != times.len() {
+        return Err(lines[1].error(&format!("{} distances", times.len())));
+    }
+    return Ok(Races { times: times, distances: distances });
+}
+
+/// Multiplies together the number of ways to beat the record in each race
+pub fn part1(races: &Races) -> Result<i64, Overflow> {
+    let times = &races.times;
+    let distances = &races.distances;
+    debug!("times: {:?}", times);
+    debug!("distances: {:?}", distances);
+
+    let mut subtotal: i64 = 1;
+    for i in 0..times.len() {
+        for j in 0..(times[i] / 2 + 1) {
+            if beats_record(times[i], j, distances[i]) {
+                let num_ways = (times[i] + 1) - 2 * j;
+                subtotal = subtotal.checked_mul(num_ways).ok_or_else(|| Overflow::new(Day06::DAY, "product of ways to win"))?;
+                debug!("Time {}, Distance {} -> {}", times[i], distances[i], num_ways);
+                break;
+            }
+        }
+    }
+
+    return Ok(subtotal);
+}
+
+/// Counts the ways to beat the record when the columns are really the digits of one long race
+pub fn part2(races: &Races) -> Result<i64, Overflow> {
+    // Kerning: the columns are really the digits of a single race
+    let time = join_digits(&races.times)?;
+    let distance = join_digits(&races.distances)?;
+    debug!("time: {}, distance: {}", time, distance);
+
+    for i in 1..(time / 2 + 1) {
+        if beats_record(time, i, distance) {
+            let num_ways = (time + 1) - 2 * i;
+            debug!("Time {}, Distance {} -> {}", time, distance, num_ways);
+            return Ok(num_ways);
+        }
+    }
+    return Ok(0);
+}
+
+// Whether holding the button for hold_time goes further than the record, computed in 128 bits since the distance
+// of a long race does not fit in 64
+fn beats_record(time: i64, hold_time: i64, record: i64) -> bool {
+    return (hold_time as i128) * ((time - hold_time) as i128) > record as i128;
+}
+
+// Reads the numbers of a line as one number, or 0 if there are none
+fn join_digits(nums: &Vec<i64>) -> Result<i64, Overflow> {
+    return match nums.iter().map(|n| n.to_string()).collect::<String>().parse::<i64>() {
+        Ok(n) => Ok(n),
+        Err(e) if *e.kind() == IntErrorKind::PosOverflow => Err(Overflow::new(Day06::DAY, "race joined from the columns")),
+        Err(_) => Ok(0)
+    };
+}
@@ -0,0 +1,147 @@
+//! Day 7: Camel Cards
+//!
+//! Ranks poker-like hands of cards by type and then card by card, and totals the bids weighted by rank
+
+#![warn(missing_docs)]
+
+use std::collections::HashMap;
+use common::{example, example::Example, parse, Answer, Overflow, ParseError, Solution, SolveError};
+
+/// Solution for day 7, for use with the `aoc` runner
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    const EXAMPLES: &'static [Example] = &[example!("example")];
+    type Input = Vec<(String, i64)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(hands: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(hands)?.into());
+    }
+
+    fn part2(hands: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(hands)?.into());
+    }
+}
+
+/// Reads one `(hand, bid)` pair per line, where a hand is five of `AKQJT98765432`
+pub fn parse(input: &str) -> Result<Vec<(String, i64)>, ParseError> {
+    let mut hand_bid_pairs = Vec::new();
+    for l in parse::lines(Day07::DAY, input) {
+        let (hand, bid_str) = l.split_once(" ")?;
+        if hand.len() != 5 {
+            return Err(hand.error("a hand of 5 cards"));
+        }
+        hand.check_chars(|c| "23456789TJQKA".contains(c), "a card, one of '23456789TJQKA'")?;
+        let bid: i64 = bid_str.parse()?;
+        hand_bid_pairs.push((String::from(hand.as_str()), bid));
+    }
+    return Ok(hand_bid_pairs);
+}
+
+/// Total winnings, with the weakest hand ranked 1
+pub fn part1(hands: &Vec<(String, i64)>) -> Result<i64, Overflow> {
+    // Hand is stored as a 6-digit number in base-13
+    fn hand_order(hand: &str) -> usize {
+        const CARD_ORDER: &str = "23456789TJQKA";
+
+        // Get rank of hand type
+        let mut card_count = HashMap::new();
+        for c in hand.chars() {
+            *card_count.entry(c).or_insert(0) += 1;
+        }
+        let mut card_dist: Vec<&i32> = card_count.values().collect();
+        card_dist.sort_unstable();
+        let hand_rank = match card_dist[..] {
+            [5] => 6,
+            [1, 4] => 5,
+            [2, 3] => 4,
+            [1, 1, 3] => 3,
+            [1, 2, 2] => 2,
+            [1, 1, 1, 2] => 1,
+            _ => 0
+        };
+
+        // Get rank of each card
+        let mut subtotal = hand_rank;
+        for c in hand.chars() {
+            let card_rank = CARD_ORDER.find(c).unwrap_or_default();
+            subtotal *= CARD_ORDER.len();
+            subtotal += card_rank;
+        }
+
+        return subtotal;
+    }
+
+    let mut hand_bid_pairs: Vec<(&str, i64)> = hands.iter().map(|(hand, bid)| (hand.as_str(), *bid)).collect();
+
+    // Sort hands by rank and compute output
+    hand_bid_pairs.sort_by_key(|tup| hand_order(tup.0));
+    return total_winnings(&hand_bid_pairs);
+}
+
+/// Total winnings when `J` cards are jokers, which act as whichever card makes the strongest type but are the weakest card in ties
+pub fn part2(hands: &Vec<(String, i64)>) -> Result<i64, Overflow> {
+    // Hand is stored as a 6-digit number in base-13
+    fn hand_order(hand: &str) -> usize {
+        const CARD_ORDER: &str = "J23456789TQKA";
+
+        // Get rank of hand type (substituting each possible value of J)
+        // Using the same value for each J is always optimal
+        let mut max_hand_rank = 0;
+        for joker_sub in CARD_ORDER[1..].chars() {
+            let mut card_count = HashMap::new();
+            for mut c in hand.chars() {
+                if c == 'J' {
+                    c = joker_sub;
+                }
+                *card_count.entry(c).or_insert(0) += 1;
+            }
+            let mut card_dist: Vec<&i32> = card_count.values().collect();
+            card_dist.sort_unstable();
+            let hand_rank = match card_dist[..] {
+                [5] => 6,
+                [1, 4] => 5,
+                [2, 3] => 4,
+                [1, 1, 3] => 3,
+                [1, 2, 2] => 2,
+                [1, 1, 1, 2] => 1,
+                _ => 0
+            };
+
+            if hand_rank > max_hand_rank {
+                max_hand_rank = hand_rank;
+            }
+        }
+
+        // Get rank of each card
+        let mut subtotal = max_hand_rank;
+        for c in hand.chars() {
+            let card_rank = CARD_ORDER.find(c).unwrap_or_default();
+            subtotal *= CARD_ORDER.len();
+            subtotal += card_rank;
+        }
+
+        return subtotal;
+    }
+
+    let mut hand_bid_pairs: Vec<(&str, i64)> = hands.iter().map(|(hand, bid)| (hand.as_str(), *bid)).collect();
+
+    // Sort hands by rank and compute output
+    hand_bid_pairs.sort_by_key(|tup| hand_order(tup.0));
+    return total_winnings(&hand_bid_pairs);
+}
+
+// Sums each bid times its hand's rank, given the hands from weakest to strongest
+fn total_winnings(sorted_hand_bid_pairs: &Vec<(&str, i64)>) -> Result<i64, Overflow> {
+    let mut subtotal: i64 = 0;
+    for (i, (_, bid)) in sorted_hand_bid_pairs.iter().enumerate() {
+        let winnings = (i as i64 + 1).checked_mul(*bid).ok_or_else(|| Overflow::new(Day07::DAY, "total winnings"))?;
+        subtotal = subtotal.checked_add(winnings).ok_or_else(|| Overflow::new(Day07::DAY, "total winnings"))?;
+    }
+    return Ok(subtotal);
+}
@@ -0,0 +1,139 @@
+//! Day 8: Haunted Wasteland
+//!
+//! Follows left/right instructions around a network of nodes
+
+#![warn(missing_docs)]
+
+use std::collections::{HashMap, HashSet};
+use common::{debug, example, example::Example, parse, Answer, Overflow, ParseError, Solution, SolveError};
+
+/// Solution for day 8, for use with the `aoc` runner
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    const EXAMPLES: &'static [Example] = &[
+        example!("example-part1"),
+        example!("example-part1-b"),
+        example!("example-part2")
+    ];
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(network: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(network)?.into());
+    }
+
+    fn part2(network: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(network)?.into());
+    }
+}
+
+/// The instructions and the nodes they are followed through
+pub struct Network {
+    /// Instructions of `L` and `R`, repeated as needed
+    pub move_sequence: String,
+    /// Left and right destinations of each node
+    pub node_map: HashMap<String, (String, String)>
+}
+
+/// Reads the instruction line, a blank line, then one `AAA = (BBB, CCC)` node per line
+/// Every destination must be one of the listed nodes
+pub fn parse(input: &str) -> Result<Network, ParseError> {
+    let sections = parse::sections(Day08::DAY, input);
+    if sections.len() < 2 {
+        return Err(parse::end_of_input(Day08::DAY, input, "a move sequence and a list of nodes"));
+    }
+    let move_line = sections[0][0];
+    move_line.check_chars(|c| c == 'L' || c == 'R', "'L' or 'R'")?;
+
+    // Convert nodes to HashMap
+    let mut node_map = HashMap::new();
+    let mut dest_spans = Vec::new();
+    for l in &sections[1] {
+        let (source_node, dest_str) = l.split_once(" = ")?;
+        let (left_node, right_node) = dest_str.strip_prefix("(")?.strip_suffix(")")?.split_once(", ")?;
+        node_map.insert(String::from(source_node.as_str()), (String::from(left_node.as_str()), String::from(right_node.as_str())));
+        dest_spans.extend([left_node, right_node]);
+    }
+    for dest in dest_spans {
+        if !node_map.contains_key(dest.as_str()) {
+            return Err(dest.error("a node from the list"));
+        }
+    }
+    return Ok(Network { move_sequence: String::from(move_line.as_str()), node_map: node_map });
+}
+
+/// Counts the steps from `AAA` to `ZZZ`
+/// Fails if there is no `AAA` node, which is allowed since inputs for part 2 alone need not have one, or if the path
+/// from `AAA` loops without reaching `ZZZ`
+pub fn part1(network: &Network) -> Result<i64, SolveError> {
+    if !network.node_map.contains_key("AAA") {
+        return Err(SolveError::unsolvable(Day08::DAY, "there is no node AAA to start from"));
+    }
+    return count_steps(network, "AAA", |node| node == "ZZZ")
+        .ok_or_else(|| SolveError::unsolvable(Day08::DAY, "the path from AAA loops without reaching ZZZ"));
+}
+
+/// Counts the steps until paths started from every node ending in `A` are all on nodes ending in `Z` at once
+/// Fails if the path from any of those nodes loops without reaching a node ending in `Z`
+pub fn part2(network: &Network) -> Result<i64, SolveError> {
+    let node_map = &network.node_map;
+
+    // Traverse nodes, calculate path length for each start node
+    let mut path_lengths = HashMap::new();
+    for start_node in node_map.keys().filter(|n| n.ends_with("A")) {
+        let Some(num_steps) = count_steps(network, start_node, |node| node.ends_with("Z")) else {
+            return Err(SolveError::unsolvable(Day08::DAY, &format!("the path from {start_node} loops without reaching a node ending in Z")));
+        };
+        path_lengths.insert(start_node, num_steps);
+    }
+    debug!("path_lengths: {:?}", path_lengths);
+
+    // Calculate LCM of all path lengths, required to make all paths reach __Z simultaneously
+    let mut lcm: i64 = 1;
+    for step_count in path_lengths.values() {
+        lcm = (step_count / gcd(*step_count, lcm)).checked_mul(lcm).ok_or_else(|| Overflow::new(Day08::DAY, "least common multiple of the path lengths"))?;
+    }
+    return Ok(lcm);
+}
+
+/// Counts the steps from `start` until a node that `is_end` accepts
+/// Returns None once the path is back on a node at the same point of the instructions, as it then repeats forever
+fn count_steps(network: &Network, start: &str, is_end: impl Fn(&str) -> bool) -> Option<i64> {
+    let node_map = &network.node_map;
+    let mut seen = HashSet::new();
+    let mut curr_node = start;
+    let mut num_steps = 0;
+    loop {
+        for (i, dir) in network.move_sequence.chars().enumerate() {
+            if !seen.insert((curr_node, i)) {
+                return None;
+            }
+            curr_node = match dir {
+                'L' => node_map.get(curr_node).unwrap().0.as_str(),
+                'R' => node_map.get(curr_node).unwrap().1.as_str(),
+                _ => unreachable!()
+            };
+            num_steps += 1;
+            if is_end(curr_node) {
+                return Some(num_steps);
+            }
+        }
+    }
+}
+
+fn gcd(mut a: i64, mut b: i64) -> i64 {
+    while a != b {
+        if a > b {
+            a = a - b;
+        }
+        else {
+            b = b - a;
+        }
+    }
+    return a;
+}
\ No newline at end of file
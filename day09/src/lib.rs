@@ -0,0 +1,95 @@
+//! Day 9: Mirage Maintenance
+//!
+//! Extrapolates sensor histories by repeatedly taking differences until they are all zero
+
+#![warn(missing_docs)]
+
+use common::{example, example::Example, parse, Answer, Overflow, ParseError, Solution, SolveError};
+
+/// Solution for day 9, for use with the `aoc` runner
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    const EXAMPLES: &'static [Example] = &[example!("example")];
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(histories: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(histories)?.into());
+    }
+
+    fn part2(histories: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(histories)?.into());
+    }
+}
+
+/// Reads one history of space-separated numbers per line
+pub fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    let mut histories = Vec::new();
+    for l in parse::lines(Day09::DAY, input) {
+        let mut history = Vec::new();
+        for n in l.split(" ") {
+            history.push(n.parse::<i64>()?);
+        }
+        histories.push(history);
+    }
+    return Ok(histories);
+}
+
+/// Sums the next value of each history
+pub fn part1(histories: &Vec<Vec<i64>>) -> Result<i64, Overflow> {
+    let mut subtotal: i64 = 0;
+    for nums in histories {
+        let mut nth_order_diffs = difference_table(nums)?;
+
+        // Calculate next value in sequence
+        nth_order_diffs.last_mut().unwrap().push(0);
+        for i in (1..nth_order_diffs.len()).rev() {
+            let next_diff_val = nth_order_diffs[i - 1].last().unwrap().checked_add(*nth_order_diffs[i].last().unwrap()).ok_or_else(overflow)?;
+            nth_order_diffs[i - 1].push(next_diff_val);
+        }
+        subtotal = subtotal.checked_add(*nth_order_diffs[0].last().unwrap()).ok_or_else(overflow)?;
+    }
+    return Ok(subtotal);
+}
+
+/// Sums the value before the start of each history
+pub fn part2(histories: &Vec<Vec<i64>>) -> Result<i64, Overflow> {
+    let mut subtotal: i64 = 0;
+    for nums in histories {
+        let mut nth_order_diffs = difference_table(nums)?;
+
+        // Calculate next value in sequence
+        nth_order_diffs.last_mut().unwrap().insert(0, 0);
+        for i in (1..nth_order_diffs.len()).rev() {
+            let prev_diff_val = nth_order_diffs[i - 1][0].checked_sub(nth_order_diffs[i][0]).ok_or_else(overflow)?;
+            nth_order_diffs[i - 1].insert(0, prev_diff_val);
+        }
+        subtotal = subtotal.checked_add(nth_order_diffs[0][0]).ok_or_else(overflow)?;
+        //dbg!(&nth_order_diffs);
+    }
+    return Ok(subtotal);
+}
+
+fn overflow() -> Overflow {
+    return Overflow::new(Day09::DAY, "extrapolated value");
+}
+
+// The history followed by its differences, its differences' differences and so on, until they are all 0
+fn difference_table(nums: &Vec<i64>) -> Result<Vec<Vec<i64>>, Overflow> {
+    let mut nth_order_diffs = Vec::new();
+    nth_order_diffs.push(nums.clone());
+    while !nth_order_diffs.last().unwrap().iter().all(|n| *n == 0) {
+        let prev_diff_vec = nth_order_diffs.last().unwrap();
+        let mut diffs = Vec::new();
+        for i in 1..prev_diff_vec.len() {
+            diffs.push(prev_diff_vec[i].checked_sub(prev_diff_vec[i - 1]).ok_or_else(overflow)?);
+        }
+        nth_order_diffs.push(diffs);
+    }
+    return Ok(nth_order_diffs);
+}
\ No newline at end of file
@@ -0,0 +1,196 @@
+//! Day 10: Pipe Maze
+//!
+//! Follows the loop of pipes through the start tile `S`
+
+#![warn(missing_docs)]
+
+use common::{example, example::Example, log, parse, trace, Answer, Direction, Grid, ParseError, Solution, SolveError};
+
+/// Solution for day 10, for use with the `aoc` runner
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    const EXAMPLES: &'static [Example] = &[
+        example!("example-part1"),
+        example!("example-part1-b"),
+        example!("example-part2"),
+        example!("example-part2-b"),
+        example!("example-part2-c")
+    ];
+    type Input = Tiles;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(tiles: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(tiles).into());
+    }
+
+    fn part2(tiles: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(tiles).into());
+    }
+}
+
+/// Tile grid and the position of `S` within it
+pub struct Tiles {
+    /// Pipe characters by row and column
+    pub grid: Grid<char>,
+    /// Row and column of `S`
+    pub start_pos: (usize, usize)
+}
+
+#[derive(Copy, Clone, Debug)]
+enum TileType {
+    NS,
+    EW,
+    NE,
+    NW,
+    SW,
+    SE,
+    G,
+    S
+}
+
+// Directions a tile's pipe connects in, where the start tile could connect in any
+fn get_tile_connections(tile_type: TileType) -> &'static [Direction] {
+    match tile_type {
+        TileType::NS => &[Direction::Up, Direction::Down],
+        TileType::EW => &[Direction::Left, Direction::Right],
+        TileType::NE => &[Direction::Up, Direction::Right],
+        TileType::NW => &[Direction::Up, Direction::Left],
+        TileType::SW => &[Direction::Down, Direction::Left],
+        TileType::SE => &[Direction::Down, Direction::Right],
+        TileType::G => &[],
+        TileType::S => &Direction::ALL
+    }
+}
+
+/// Reads the pipe grid, which must contain a single start tile `S`
+pub fn parse(input: &str) -> Result<Tiles, ParseError> {
+    let grid = parse::char_grid(Day10::DAY, input, "|-LJ7F.S")?;
+    return match grid.position(|ch| *ch == 'S') {
+        Some(start_pos) => Ok(Tiles { grid: grid, start_pos: start_pos }),
+        None => Err(parse::end_of_input(Day10::DAY, input, "a start tile 'S'"))
+    };
+}
+
+fn get_tile_type(c: char) -> TileType {
+    match c {
+        '|' => TileType::NS,
+        '-' => TileType::EW,
+        'L' => TileType::NE,
+        'J' => TileType::NW,
+        '7' => TileType::SW,
+        'F' => TileType::SE,
+        'S' => TileType::S,
+        _ => TileType::G
+    }
+}
+
+// Follows the loop from the start, marking every tile on it
+fn trace_loop(tiles: &Tiles) -> Grid<bool> {
+    let grid = &tiles.grid;
+    let mut is_boundary = grid.map(|_| false);
+    let mut curr_pos = tiles.start_pos;
+    let mut curr_tile = TileType::S;
+
+    is_boundary[curr_pos] = true;
+    loop {
+        // Move to the first unvisited tile that the current pipe connects to and that connects back
+        let next = get_tile_connections(curr_tile).iter().find_map(|dir| {
+            let next_pos = grid.step(curr_pos, dir.offset())?;
+            let next_tile = get_tile_type(grid[next_pos]);
+            if get_tile_connections(next_tile).contains(&dir.reverse()) && !is_boundary[next_pos] {
+                return Some((next_pos, next_tile));
+            }
+            return None;
+        });
+
+        // If no unvisited connections can be found, the end has been reached
+        let Some((next_pos, next_tile)) = next else {
+            break;
+        };
+        curr_pos = next_pos;
+        curr_tile = next_tile;
+        is_boundary[curr_pos] = true;
+    }
+    return is_boundary;
+}
+
+/// Counts the steps along the loop to the point farthest from the start
+pub fn part1(tiles: &Tiles) -> usize {
+    let num_visited = trace_loop(tiles).iter().filter(|(_, b)| **b).count();
+    return num_visited / 2;
+}
+
+/// Counts the tiles enclosed by the loop, by counting the loop crossings to the left of each tile
+pub fn part2(tiles: &Tiles) -> usize {
+    let mut grid = tiles.grid.clone();
+    let start_pos = tiles.start_pos;
+    let is_boundary = trace_loop(tiles);
+    let boundary_at = |dir: Direction| tiles.grid.step(start_pos, dir.offset()).is_some_and(|pos| is_boundary[pos]);
+
+    // Replace 'S' with matching boundary shape
+    if boundary_at(Direction::Up) {
+        if boundary_at(Direction::Left) {
+            grid[start_pos] = 'J';
+        }
+        else if boundary_at(Direction::Right) {
+            grid[start_pos] = 'L';
+        }
+        else {
+            grid[start_pos] = '|';
+        }
+    }
+    else {
+        if !boundary_at(Direction::Down) {
+            grid[start_pos] = '-';
+        }
+        else if boundary_at(Direction::Left) {
+            grid[start_pos] = '7';
+        }
+        else {
+            grid[start_pos] = 'F';
+        }
+    }
+    if log::enabled(log::Level::Trace) {
+        trace!("{}", Grid::from_fn(grid.width(), grid.height(), |pos| if is_boundary[pos] { grid[pos] } else { '.' }));
+    }
+
+    // Check if each point is interior by counting boundary crossings
+    let mut num_interior_tiles = 0;
+    for row_num in 0..grid.height() {
+        let mut boundary_crossings = 0;
+        let mut crossing_start_tile = TileType::G;
+        for col_num in 0..grid.width() {
+            if is_boundary[(row_num, col_num)] {
+                let curr_tile = get_tile_type(grid[(row_num, col_num)]);
+                match curr_tile {
+                    TileType::NS => boundary_crossings += 1,
+                    TileType::NE | TileType::SE => crossing_start_tile = curr_tile,
+                    TileType::NW => {
+                        if let TileType::SE = crossing_start_tile {
+                            boundary_crossings += 1;
+                        }
+                        crossing_start_tile = TileType::G;
+                    },
+                    TileType::SW => {
+                        if let TileType::NE = crossing_start_tile {
+                            boundary_crossings += 1;
+                        }
+                        crossing_start_tile = TileType::G;
+                    },
+                    TileType::EW => {},
+                    _ => unreachable!()
+                }
+            }
+            else if boundary_crossings % 2 == 1 {
+                num_interior_tiles += 1;
+            }
+        }
+    }
+    
+    return num_interior_tiles;
+}
\ No newline at end of file
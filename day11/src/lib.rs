@@ -0,0 +1,118 @@
+//! Day 11: Cosmic Expansion
+//!
+//! Sums the distances between galaxies in an image of space whose empty rows and columns have expanded
+
+#![warn(missing_docs)]
+
+use common::{example, example::Example, params::{Param, Params}, parse, Answer, Grid, Overflow, ParseError, Solution, SolveError};
+
+/// Solution for day 11, for use with the `aoc` runner
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    const PARAMS: &'static [Param] = &[
+        Param { name: "expansion", description: "how many times larger each empty row and column becomes in part 2, default 1000000", is_valid: is_valid_expansion }
+    ];
+    const EXAMPLES: &'static [Example] = &[
+        example!("example"),
+        example!("example-part2-100x"),
+        example!("example-part2-10x")
+    ];
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(image: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(image)?.into());
+    }
+
+    fn part2(image: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(image)?.into());
+    }
+
+    fn part2_with(image: &Self::Input, params: &Params) -> Result<Answer, SolveError> {
+        return Ok(sum_galaxy_distances(image, params.get_or("expansion", EXPANSION) - 1)?.into());
+    }
+}
+
+/// How many times larger each empty row and column is in part 2
+pub const EXPANSION: u64 = 1000000;
+
+fn is_valid_expansion(value: &str) -> bool {
+    return value.parse::<u64>().is_ok_and(|n| n >= 1);
+}
+
+/// Reads the image, made of `.` for empty space and `#` for galaxies
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    return parse::char_grid(Day11::DAY, input, ".#");
+}
+
+/// Sums the shortest distances between all pairs of galaxies when empty rows and columns are twice as large
+pub fn part1(image: &Grid<char>) -> Result<u64, Overflow> {
+    return sum_galaxy_distances(image, 1);
+}
+
+/// Sums the shortest distances between all pairs of galaxies when empty rows and columns are a million times as large
+pub fn part2(image: &Grid<char>) -> Result<u64, Overflow> {
+    return sum_galaxy_distances(image, EXPANSION - 1);
+}
+
+// Sums the distances between all pairs of galaxies, after inserting extra_space rows/columns for each empty one
+fn sum_galaxy_distances(image: &Grid<char>, extra_space: u64) -> Result<u64, Overflow> {
+    let overflow = || Overflow::new(Day11::DAY, "sum of distances");
+
+    // Find empty rows and columns
+    let mut empty_rows = Vec::new();
+    for i in 0..image.height() {
+        if image.row(i).iter().all(|c| *c == '.') {
+            empty_rows.push(i);
+        }
+    }
+    let mut empty_cols = Vec::new();
+    for i in 0..image.width() {
+        if image.column(i).all(|c| *c == '.') {
+            empty_cols.push(i);
+        }
+    }
+    empty_rows.push(std::usize::MAX); // Add padding value to simplify coord calculation
+    empty_cols.push(std::usize::MAX);
+    //println!("{:?}", empty_rows);
+    //println!("{:?}", empty_cols);
+
+    // Calculate coordinates of each galaxy
+    let mut galaxy_coords = Vec::new();
+    let mut empty_row_offset = 0;
+    for i in 0..image.height() {
+        if empty_rows[empty_row_offset] == i {
+            empty_row_offset += 1;
+            continue;
+        }
+        let mut empty_col_offset = 0;
+        for j in 0..image.width() {
+            if empty_cols[empty_col_offset] == j {
+                empty_col_offset += 1;
+            }
+            if image[(i, j)] == '#' {
+                let row = (empty_row_offset as u64).checked_mul(extra_space).and_then(|n| n.checked_add(i as u64)).ok_or_else(overflow)?;
+                let col = (empty_col_offset as u64).checked_mul(extra_space).and_then(|n| n.checked_add(j as u64)).ok_or_else(overflow)?;
+                galaxy_coords.push((row, col));
+            }
+        }
+    }
+    //println!("{:?}", galaxy_coords);
+
+    // Calculate all pair distances
+    let mut subtotal: u64 = 0;
+    for i in 0..galaxy_coords.len() {
+        for j in (i + 1)..galaxy_coords.len() {
+            let dist_row = (galaxy_coords[i].0).abs_diff(galaxy_coords[j].0);
+            let dist_col = (galaxy_coords[i].1).abs_diff(galaxy_coords[j].1);
+            subtotal = dist_row.checked_add(dist_col).and_then(|d| subtotal.checked_add(d)).ok_or_else(overflow)?;
+            //println!("Dist from {} to {}: {}", i, j, dist_row + dist_col);
+        }
+    }
+    return Ok(subtotal);
+}
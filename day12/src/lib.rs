@@ -0,0 +1,216 @@
+//! Day 12: Hot Springs
+//!
+//! Counts the arrangements of operational and damaged springs that match the damaged group sizes of each row
+
+#![warn(missing_docs)]
+
+use common::{example, example::Example, parse, trace, Answer, Overflow, ParseError, Solution, SolveError};
+
+/// Solution for day 12, for use with the `aoc` runner
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    const EXAMPLES: &'static [Example] = &[example!("example")];
+    type Input = Vec<(Vec<char>, Vec<usize>)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(records: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(records)?.into());
+    }
+
+    fn part2(records: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(records)?.into());
+    }
+}
+
+/// Reads each line into its spring conditions and contiguous damaged group sizes
+pub fn parse(input: &str) -> Result<Vec<(Vec<char>, Vec<usize>)>, ParseError> {
+    let mut records = Vec::new();
+    for ln in parse::lines(Day12::DAY, input) {
+        let (condition_str, groups_str) = ln.split_once(" ")?;
+        condition_str.check_chars(|c| ".#?".contains(c), "one of '.#?'")?;
+        let mut contiguous_groups = Vec::new();
+        for n in groups_str.split(",") {
+            contiguous_groups.push(n.parse::<usize>()?);
+        }
+        records.push((condition_str.as_str().chars().collect(), contiguous_groups));
+    }
+    return Ok(records);
+}
+
+/// Sums the arrangement counts of the rows, by trying every assignment of the unknown springs
+pub fn part1(records: &Vec<(Vec<char>, Vec<usize>)>) -> Result<u64, Overflow> {
+    let overflow = || Overflow::new(Day12::DAY, "number of arrangements");
+    let mut subtotal: u64 = 0;
+    for (condition_str, contiguous_groups) in records {
+        // Alternate between working/damaged for each unknown spring, check if it matches group sizes
+        let mut valid_arrangements = 0;
+        let num_unknown = condition_str.iter().filter(|ch| **ch == '?').count();
+        let num_bitmaps = 1u64.checked_shl(num_unknown as u32).ok_or_else(overflow)?;
+        for mut bitmap in 0..num_bitmaps {
+            // Count group sizes for given arrangement
+            let mut arrangement_groups = Vec::new();
+            let mut consec_damaged = 0;
+            for ch in condition_str {
+                let is_damaged = match ch {
+                    '.' => false,
+                    '#' => true,
+                    '?' => {
+                        let output = (bitmap % 2) == 0;
+                        bitmap /= 2;
+                        output
+                    },
+                    _ => unreachable!()
+                };
+                if is_damaged {
+                    consec_damaged += 1;
+                }
+                else if consec_damaged > 0 {
+                    arrangement_groups.push(consec_damaged);
+                    consec_damaged = 0;
+                }
+            }
+            if consec_damaged > 0 {
+                arrangement_groups.push(consec_damaged);
+            }
+            //println!("{:?}", arrangement_groups);
+
+            // Check if group sizes match
+            if arrangement_groups == *contiguous_groups {
+                valid_arrangements += 1;
+            }
+        }
+        //println!("{} valid arrangements", valid_arrangements);
+        subtotal = subtotal.checked_add(valid_arrangements).ok_or_else(overflow)?;
+    }
+    return Ok(subtotal);
+}
+
+/// Sums the arrangement counts of the rows unfolded five times, joined by unknown springs, using [`count_arrangements`]
+pub fn part2(records: &Vec<(Vec<char>, Vec<usize>)>) -> Result<i64, Overflow> {
+    let mut subtotal: i64 = 0;
+    for (input_condition_str, input_contiguous_groups) in records {
+        let (condition_data, condition_groups) = unfold(input_condition_str, input_contiguous_groups, 5);
+        let arrangement_count = count_arrangements(&condition_data, &condition_groups)?;
+        trace!("{}", arrangement_count);
+        subtotal = subtotal.checked_add(arrangement_count).ok_or_else(|| Overflow::new(Day12::DAY, "number of arrangements"))?;
+    }
+
+    return Ok(subtotal);
+}
+
+/// Repeats a row `copies` times, joining the copies of the spring conditions with unknown springs
+pub fn unfold(condition_str: &Vec<char>, contiguous_groups: &Vec<usize>, copies: usize) -> (Vec<char>, Vec<usize>) {
+    let mut condition_data = Vec::new();
+    for _ in 0..copies {
+        for ch in condition_str {
+            condition_data.push(*ch);
+        }
+        condition_data.push('?');
+    }
+    condition_data.pop();
+
+    let mut condition_groups = Vec::new();
+    for _ in 0..copies {
+        for n in contiguous_groups {
+            condition_groups.push(*n);
+        }
+    }
+    return (condition_data, condition_groups);
+}
+
+/// Counts the ways the unknown springs (`'?'`) in a row can each be operational (`'.'`) or damaged (`'#'`)
+/// so that the runs of damaged springs have exactly the given sizes, in order
+pub fn count_arrangements(condition_data: &Vec<char>, condition_groups: &Vec<usize>) -> Result<i64, Overflow> {
+    let mut results: Vec<Vec<Option<i64>>> = vec![vec![None; condition_groups.len() + 1]; condition_data.len() + 1];
+    return count_arrangements_from(&mut results, condition_data, condition_groups, 0, 0);
+}
+
+// Recursively count arrangements of the springs from start_index on, with num_groups_used groups already placed
+// results[start_index][num_groups_used] memoizes each count
+fn count_arrangements_from(results: &mut Vec<Vec<Option<i64>>>, 
+    condition_data: &Vec<char>, 
+    condition_groups: &Vec<usize>,
+    start_index: usize, 
+    num_groups_used: usize) -> Result<i64, Overflow> {
+
+    // Check if memoized result exists
+    if let Some(count) = results[start_index][num_groups_used] {
+        //println!("Returned memoized value results[{}][{}] = {}", start_index, num_groups_used, count);
+        return Ok(count);
+    }
+
+    // Check base cases
+    if start_index == condition_data.len() {
+        if num_groups_used == condition_groups.len() {
+            results[start_index][num_groups_used] = Some(1);
+            return Ok(1);
+        }
+        else {
+            results[start_index][num_groups_used] = Some(0);
+            return Ok(0);
+        }
+    }
+    else if num_groups_used == condition_groups.len() {
+        // Make sure all following values are not damaged if no groups remain
+        if condition_data[start_index..].iter().all(|ch| (*ch == '.') | (*ch == '?')) {
+            results[start_index][num_groups_used] = Some(1);
+            return Ok(1);
+        }
+        else {
+            results[start_index][num_groups_used] = Some(0);
+            return Ok(0);
+        }
+    }
+
+    // Check if next group can be placed starting at start_index (following k elements are '#' or '?')
+    let mut arrangement_count = 0;
+    let group_end_index = start_index + condition_groups[num_groups_used];
+    if group_end_index > condition_data.len() {
+        results[start_index][num_groups_used] = Some(0);
+        return Ok(0);
+    }
+    // Total arrangements if next group is chosen to start at this position
+    if condition_data[start_index..group_end_index].iter().all(|ch| (*ch == '#') | (*ch == '?')) {
+        // Check if there are k instances of '#' or '?' followed by '.' or '?' or end of list to terminate group
+        if group_end_index == condition_data.len() {
+            arrangement_count = add_count(arrangement_count, count_arrangements_from(results, condition_data, condition_groups, group_end_index, num_groups_used + 1)?)?;
+        }
+        else if condition_data[group_end_index] != '#' {
+            // Increase start index by 1 to cover terminating symbol
+            arrangement_count = add_count(arrangement_count, count_arrangements_from(results, condition_data, condition_groups, group_end_index + 1, num_groups_used + 1)?)?;
+        }
+    }
+    // Total arrangements if next group is chosen to not start at this position
+    if condition_data[start_index] != '#' {
+        arrangement_count = add_count(arrangement_count, count_arrangements_from(results, condition_data, condition_groups, start_index + 1, num_groups_used)?)?;
+    }
+
+    // Memoize result and then return
+    results[start_index][num_groups_used] = Some(arrangement_count);
+    //print_results(&results);
+    return Ok(arrangement_count);
+}
+
+fn add_count(count: i64, more: i64) -> Result<i64, Overflow> {
+    return count.checked_add(more).ok_or_else(|| Overflow::new(Day12::DAY, "number of arrangements"));
+}
+
+#[allow(dead_code)]
+fn print_results(results: &Vec<Vec<Option<i64>>>) {
+    let mut results_str = String::new();
+    for i in 0..results.len() {
+        for j in 0..results[0].len() {
+            match results[i][j] {
+                Some(n) => results_str += &format!("{:5}", n),
+                None => results_str += "    -"
+            }
+        }
+        results_str.push('\n');
+    }
+    trace!("{results_str}");
+}
\ No newline at end of file
@@ -0,0 +1,139 @@
+//! Day 13: Point of Incidence
+//!
+//! Finds the lines of reflection in patterns of ash and rocks
+
+#![warn(missing_docs)]
+
+use std::cmp;
+use common::{example, example::Example, parse, trace, Answer, Grid, ParseError, Solution, SolveError};
+
+/// Solution for day 13, for use with the `aoc` runner
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    const EXAMPLES: &'static [Example] = &[example!("example")];
+    type Input = Vec<Grid<char>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(grids: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(grids).into());
+    }
+
+    fn part2(grids: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(grids).into());
+    }
+}
+
+/// Reads the patterns, separated by blank lines
+pub fn parse(input: &str) -> Result<Vec<Grid<char>>, ParseError> {
+    // Split lines into grids
+    let mut grids = Vec::new();
+    for grid_lines in parse::sections(Day13::DAY, input) {
+        grids.push(parse::char_grid_lines(&grid_lines, ".#")?);
+    }
+    return Ok(grids);
+}
+
+/// Summarizes the reflections: columns left of each vertical line plus 100 times the rows above each horizontal line
+pub fn part1(grids: &Vec<Grid<char>>) -> usize {
+    // Find vertical and horizontal mirroring lines for each grid
+    let mut subtotal = 0;
+    for grd in grids.iter() {
+        let num_rows = grd.height();
+        let num_cols = grd.width();
+
+        // Find any lines of vertical symmetry
+        for vert_mp_candidate in 1..num_cols {
+            let num_mirrored = cmp::min(vert_mp_candidate, num_cols - vert_mp_candidate); // Prevent out of bounds
+            // Check if each column is mirrored correctly
+            let is_valid = (0..num_mirrored).all(|mirror_offset| grd.column(vert_mp_candidate - 1 - mirror_offset).eq(grd.column(vert_mp_candidate + mirror_offset)));
+            if is_valid {
+                //println!("Grid {}, Vertical midpoint: {}", n, vert_mp_candidate);
+                subtotal += vert_mp_candidate;
+            }
+        }
+
+        // Find line of horizontal symmetry
+        for horiz_mp_candidate in 1..num_rows {
+            let num_mirrored = cmp::min(horiz_mp_candidate, num_rows - horiz_mp_candidate); // Prevent out of bounds
+            // Check if each row is mirrored correctly
+            let is_valid = (0..num_mirrored).all(|mirror_offset| grd.row(horiz_mp_candidate - 1 - mirror_offset) == grd.row(horiz_mp_candidate + mirror_offset));
+            if is_valid {
+                //println!("Grid {}, Horizontal midpoint: {}", n, horiz_mp_candidate);
+                subtotal += 100 * horiz_mp_candidate;
+            }
+        }
+    }
+
+    return subtotal;
+}
+
+/// Summarizes the reflections after fixing the one smudge in each pattern that gives a different line of reflection
+pub fn part2(grids: &Vec<Grid<char>>) -> usize {
+    let mut grids = grids.clone();
+
+    // Check all smudge positions for each grid
+    let mut subtotal = 0;
+    for (n, grd) in grids.iter_mut().enumerate() {
+        let num_rows = grd.height();
+        let num_cols = grd.width();
+
+        'smudge_pos_loop: for smudge_row in 0..num_rows {
+            for smudge_col in 0..num_cols {
+                // Flip value at smudge position
+                grd[(smudge_row, smudge_col)] = match grd[(smudge_row, smudge_col)] {
+                    '.' => '#',
+                    '#' => '.',
+                    _ => unreachable!()
+                };
+
+                // Find line of vertical symmetry (only checking lines where smudge position would be mirrored)
+                for vert_mp_candidate in 1..num_cols {
+                    let num_mirrored = cmp::min(vert_mp_candidate, num_cols - vert_mp_candidate); // Prevent out of bounds
+                    // Check if smudge position would be mirrored
+                    if !((smudge_col >= vert_mp_candidate - num_mirrored) && (smudge_col < vert_mp_candidate + num_mirrored)) {
+                        continue;
+                    }
+
+                    // Check if each column is mirrored correctly
+                    let is_valid = (0..num_mirrored).all(|mirror_offset| grd.column(vert_mp_candidate - 1 - mirror_offset).eq(grd.column(vert_mp_candidate + mirror_offset)));
+                    if is_valid {
+                        trace!("Grid {}, Smudge: ({}, {}), Vertical midpoint: {}", n, smudge_row, smudge_col, vert_mp_candidate);
+                        subtotal += vert_mp_candidate;
+                        break 'smudge_pos_loop;
+                    }
+                }
+        
+                // Find line of horizontal symmetry (only checking lines where smudge position would be mirrored)
+                for horiz_mp_candidate in 1..num_rows {
+                    let num_mirrored = cmp::min(horiz_mp_candidate, num_rows - horiz_mp_candidate); // Prevent out of bounds
+                    // Check if smudge position would be mirrored
+                    if !((smudge_row >= horiz_mp_candidate - num_mirrored) && (smudge_row < horiz_mp_candidate + num_mirrored)) {
+                        continue;
+                    }
+
+                    // Check if each row is mirrored correctly
+                    let is_valid = (0..num_mirrored).all(|mirror_offset| grd.row(horiz_mp_candidate - 1 - mirror_offset) == grd.row(horiz_mp_candidate + mirror_offset));
+                    if is_valid {
+                        trace!("Grid {}, Smudge: ({}, {}), Horizontal midpoint: {}", n, smudge_row, smudge_col, horiz_mp_candidate);
+                        subtotal += 100 * horiz_mp_candidate;
+                        break 'smudge_pos_loop;
+                    }
+                }
+                
+                // Revert value at smudge position
+                grd[(smudge_row, smudge_col)] = match grd[(smudge_row, smudge_col)] {
+                    '.' => '#',
+                    '#' => '.',
+                    _ => unreachable!()
+                };
+            }
+        }
+    }
+
+    return subtotal;
+}
\ No newline at end of file
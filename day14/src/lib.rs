@@ -0,0 +1,117 @@
+//! Day 14: Parabolic Reflector Dish
+//!
+//! Tilts a platform of round and cube-shaped rocks and measures the load on its north support beams
+
+#![warn(missing_docs)]
+
+use std::collections::HashMap;
+use common::{debug, example, example::Example, params::{self, Param, Params}, parse, trace, Answer, Grid, ParseError, Solution, SolveError};
+
+/// Solution for day 14, for use with the `aoc` runner
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    const PARAMS: &'static [Param] = &[
+        Param { name: "cycles", description: "spin cycles to run in part 2, default 1000000000", is_valid: params::parses_as::<usize> }
+    ];
+    const EXAMPLES: &'static [Example] = &[example!("example")];
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(platform: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(platform).into());
+    }
+
+    fn part2(platform: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(platform).into());
+    }
+
+    fn part2_with(platform: &Self::Input, params: &Params) -> Result<Answer, SolveError> {
+        return Ok(north_load_after_cycles(platform, params.get_or("cycles", TOTAL_CYCLES)).into());
+    }
+}
+
+/// Spin cycles run in part 2
+pub const TOTAL_CYCLES: usize = 1000000000;
+
+/// Reads the platform of round rocks (`O`), cube rocks (`#`) and empty space (`.`)
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    return parse::char_grid(Day14::DAY, input, "O#.");
+}
+
+// Rolls every round rock as far north as it goes
+fn tilt_north(grid: &mut Grid<char>) {
+    for i in 0..grid.height() {
+        for j in 0..grid.width() {
+            if grid[(i, j)] == 'O' {
+                grid[(i, j)] = '.';
+                let mut new_row = i;
+                while new_row > 0 && grid[(new_row - 1, j)] == '.' {
+                    new_row -= 1;
+                }
+                grid[(new_row, j)] = 'O';
+            }
+        }
+    }
+}
+
+// Each round rock adds the number of rows from it to the south edge, counting its own
+fn north_load(grid: &Grid<char>) -> usize {
+    return grid.iter().filter(|(_, c)| **c == 'O').map(|((row, _), _)| grid.height() - row).sum();
+}
+
+/// Total load after tilting the platform north
+pub fn part1(platform: &Grid<char>) -> usize {
+    let mut grid = platform.clone();
+    tilt_north(&mut grid);
+    return north_load(&grid);
+}
+
+/// Total load after a billion spin cycles of tilting north, west, south and east
+/// The positions repeat after a while, so only the cycles before the first repeat are simulated
+pub fn part2(platform: &Grid<char>) -> usize {
+    return north_load_after_cycles(platform, TOTAL_CYCLES);
+}
+
+/// Load on the north support beams after running the given number of spin cycles
+pub fn north_load_after_cycles(platform: &Grid<char>, total_cycles: usize) -> usize {
+    let mut grid = platform.clone();
+
+    // Run movement cycles, find when positions recur
+    // Cycle 0 is the starting position, and the last cycle run is the final position if none recur
+    let mut pos_map = HashMap::new();
+    pos_map.insert(grid.clone(), 0);
+    let mut recurring_cycle_index = total_cycles;
+    for cycle_count in 1..=total_cycles {
+        // Tilt north, then turn the platform so that west, south and east in turn face north
+        for _ in 0..4 {
+            tilt_north(&mut grid);
+            grid = grid.rotate_clockwise();
+        }
+
+        // Check if current position has already been generated, store position otherwise
+        if pos_map.contains_key(&grid) {
+            // Calculate which cycle equals the final value
+            debug!("Recurs over {} cycles", pos_map.len());
+            debug!("Cycle {} equals cycle {}", cycle_count, pos_map.get(&grid).unwrap());
+            let recurrence_start_index = pos_map.get(&grid).unwrap();
+            let recurrence_length = cycle_count - recurrence_start_index;
+            recurring_cycle_index = (total_cycles - recurrence_start_index) % recurrence_length + recurrence_start_index;
+            break;
+        }
+        else {
+            pos_map.insert(grid.clone(), cycle_count);
+        }
+
+    }
+    // Debug print
+    let final_grid = pos_map.iter().find_map(|(k, v)| 
+                                                if *v == recurring_cycle_index { Some(k) } else { None }
+                                                ).unwrap();
+    trace!("{final_grid}");
+    return north_load(final_grid);
+}
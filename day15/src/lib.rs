@@ -0,0 +1,122 @@
+//! Day 15: Lens Library
+//!
+//! Runs the HASH algorithm over an initialization sequence and arranges lenses in boxes by it
+
+#![warn(missing_docs)]
+
+use common::{example, example::Example, log, parse, trace, Answer, ParseError, Solution, SolveError};
+
+/// Solution for day 15, for use with the `aoc` runner
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    const EXAMPLES: &'static [Example] = &[example!("example")];
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(steps: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(steps).into());
+    }
+
+    fn part2(steps: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(steps).into());
+    }
+}
+
+/// Splits the initialization sequence into its comma-separated steps
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    let mut steps = Vec::new();
+    for ln in parse::lines(Day15::DAY, input) {
+        for step in ln.split(",") {
+            // Each step either removes a lens (label-) or inserts one with a focal length (label=N)
+            let label = match step.as_str().strip_suffix('-') {
+                Some(_) => step.slice(0, step.len() - 1),
+                None => {
+                    let (label, focal_length) = step.split_once("=")?;
+                    focal_length.parse::<u8>()?;
+                    label
+                }
+            };
+            if label.is_empty() {
+                return Err(label.error("a lens label"));
+            }
+            label.check_chars(|c| c.is_ascii_lowercase(), "a lowercase letter")?;
+            steps.push(String::from(step.as_str()));
+        }
+    }
+    return Ok(steps);
+}
+
+/// Sums the HASH value of every step
+pub fn part1(steps: &Vec<String>) -> u64 {
+    let mut subtotal = 0;
+    for input in steps {
+        let mut hash_value = 0;
+        for ch in input.as_bytes() {
+            hash_value += *ch as u64;
+            hash_value *= 17;
+            hash_value %= 256;
+        }
+        subtotal += hash_value;
+    }
+    return subtotal;
+}
+
+/// Total focusing power after each step inserts (`label=N`) or removes (`label-`) a lens in the box given by the HASH of its label
+pub fn part2(steps: &Vec<String>) -> usize {
+    let mut boxes: [Vec<(&str, i32)>; 256] = std::array::from_fn(|_| Vec::new());
+    for cmd in steps {
+        // Process lens removal command
+        if cmd.ends_with('-') {
+            let lens_label = &cmd[0..(cmd.len() - 1)];
+            let box_num = hash(lens_label);
+            let lens_list = &mut boxes[box_num];
+            let lens_index = (0..lens_list.len()).find(|idx| lens_list[*idx].0 == lens_label);
+            if let Some(idx) = lens_index {
+                lens_list.remove(idx);
+            }
+        }
+        // Process lens insertion command
+        else {
+            let lens_label = &cmd[0..cmd.find('=').unwrap()];
+            let focal_length: i32 = cmd[(cmd.find('=').unwrap() + 1)..].parse().unwrap();
+            let box_num = hash(lens_label);
+            let lens_list = &mut boxes[box_num];
+            match (0..lens_list.len()).find(|idx| lens_list[*idx].0 == lens_label) {
+                Some(idx) => lens_list[idx] = (lens_label, focal_length),
+                None => lens_list.push((lens_label, focal_length))
+            }
+        }
+    }
+    // Debug print
+    if log::enabled(log::Level::Trace) {
+        for box_num in 0..boxes.len() {
+            if boxes[box_num].len() > 0 {
+                let lenses: String = boxes[box_num].iter().map(|(lbl, focal_length)| format!("[{} {}]", lbl, focal_length)).collect();
+                trace!("Box {}: {}", box_num, lenses);
+            }
+        }
+    }
+    // Calculate final score
+    let mut score = 0;
+    for box_num in 0..boxes.len() {
+        for lens_idx in 0..boxes[box_num].len() {
+            score += (box_num + 1) * (lens_idx + 1) * (boxes[box_num][lens_idx].1 as usize);
+        }
+    }
+    return score;
+}
+
+fn hash(input_str: &str) -> usize {
+    let mut hash_value = 0;
+    for ch in input_str.as_bytes() {
+        hash_value += *ch as usize;
+        hash_value *= 17;
+        hash_value %= 256;
+    }
+    return hash_value;
+}
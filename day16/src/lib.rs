@@ -0,0 +1,100 @@
+//! Day 16: The Floor Will Be Lava
+//!
+//! Traces a beam of light through a grid of mirrors and splitters
+
+#![warn(missing_docs)]
+
+use common::{example, example::Example, log, parse, trace, Answer, Direction, Grid, ParseError, Solution, SolveError};
+
+/// Solution for day 16, for use with the `aoc` runner
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+    const EXAMPLES: &'static [Example] = &[example!("example")];
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(grid).into());
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(grid).into());
+    }
+}
+
+/// Reads the contraption grid of `.`, mirrors `/` and `\`, and splitters `|` and `-`
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    return parse::char_grid(Day16::DAY, input, ".|-/\\");
+}
+
+// Directions a beam leaves a tile in after entering it heading in the given direction
+fn exit_dirs(tile: char, beam_dir: Direction) -> Vec<Direction> {
+    return match tile {
+        '\\' => vec![if beam_dir.is_vertical() { beam_dir.turn_left() } else { beam_dir.turn_right() }],
+        '/' => vec![if beam_dir.is_vertical() { beam_dir.turn_right() } else { beam_dir.turn_left() }],
+        '|' if !beam_dir.is_vertical() => vec![Direction::Up, Direction::Down], // Splits when hit from the side, otherwise passes straight through
+        '-' if beam_dir.is_vertical() => vec![Direction::Left, Direction::Right],
+        _ => vec![beam_dir]
+    };
+}
+
+// Directions a beam has passed through each tile in, starting from the given tile and direction
+fn energize(grid: &Grid<char>, start_pos: (usize, usize), start_dir: Direction) -> Grid<[bool; 4]> {
+    let mut visited = grid.map(|_| [false; 4]);
+
+    // Trace all paths with DFS, stopping on tiles already visited
+    let mut beam_stack = vec![(start_pos, start_dir)];
+    while let Some((visit_pos, beam_dir)) = beam_stack.pop() {
+        // Check if current position is already visited in the current direction, mark as visited
+        if visited[visit_pos][beam_dir.index()] {
+            trace!("Revisiting {:?} in direction {:?}", visit_pos, beam_dir);
+            continue;
+        }
+        visited[visit_pos][beam_dir.index()] = true;
+        trace!("Visiting {:?}", visit_pos);
+
+        // Push new beam location(s) and direction(s) to stack, unless they leave the grid
+        for next_dir in exit_dirs(grid[visit_pos], beam_dir) {
+            if let Some(next_pos) = grid.step(visit_pos, next_dir.offset()) {
+                beam_stack.push((next_pos, next_dir));
+            }
+        }
+    }
+    return visited;
+}
+
+fn count_visited(visited: &Grid<[bool; 4]>) -> usize {
+    return visited.iter().filter(|(_, dirs)| dirs.iter().any(|v| *v)).count();
+}
+
+/// Counts the tiles energized by a beam entering the top-left corner heading right
+pub fn part1(grid: &Grid<char>) -> usize {
+    let visited = energize(grid, (0, 0), Direction::Right);
+    if log::enabled(log::Level::Trace) {
+        trace!("{}", visited.map(|dirs| if dirs.iter().any(|d| *d) { '#' } else { '.' }));
+    }
+    return count_visited(&visited);
+}
+
+/// Counts the tiles energized by the best beam entering from any edge tile
+pub fn part2(grid: &Grid<char>) -> usize {
+    let num_rows = grid.height();
+    let num_cols = grid.width();
+
+    // Find start position with greatest number of visited tiles
+    let mut starts = Vec::new();
+    for i in 0..num_rows {
+        starts.push(((i, 0), Direction::Right)); // Start from left
+        starts.push(((i, num_cols - 1), Direction::Left)); // Start from right
+    }
+    for i in 0..num_cols {
+        starts.push(((0, i), Direction::Down)); // Start from top
+        starts.push(((num_rows - 1, i), Direction::Up)); // Start from bottom
+    }
+    return starts.into_iter().map(|(start_pos, start_dir)| count_visited(&energize(grid, start_pos, start_dir))).max().unwrap();
+}
@@ -0,0 +1,88 @@
+//! Day 17: Clumsy Crucible
+//!
+//! Finds the path for a crucible through city blocks that loses the least heat, with limits on straight runs
+
+#![warn(missing_docs)]
+
+use common::{example, example::Example, parse, search, trace, Answer, Direction, Grid, ParseError, Solution, SolveError};
+
+/// Solution for day 17, for use with the `aoc` runner
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+    const EXAMPLES: &'static [Example] = &[
+        example!("example"),
+        example!("example-part2-b")
+    ];
+    type Input = Grid<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(costs: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(costs)?.into());
+    }
+
+    fn part2(costs: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(costs)?.into());
+    }
+}
+
+/// Reads the heat loss of each city block, as a grid of digits
+pub fn parse(input: &str) -> Result<Grid<usize>, ParseError> {
+    return parse::grid(Day17::DAY, input, "a digit", |c| c.to_digit(10).map(|d| d as usize));
+}
+
+/// Least heat loss from the top-left to the bottom-right block, moving at most 3 blocks in a straight line
+/// Fails if no run can end on the bottom-right block
+pub fn part1(costs: &Grid<usize>) -> Result<usize, SolveError> {
+    return least_heat_loss(costs, 1, 3);
+}
+
+/// Least heat loss for an ultra crucible, which moves 4 to 10 blocks in a straight line before turning or stopping
+/// Fails if no run can end on the bottom-right block
+pub fn part2(costs: &Grid<usize>) -> Result<usize, SolveError> {
+    return least_heat_loss(costs, 4, 10);
+}
+
+// Least heat loss to the bottom-right block when each straight run is min_run to max_run blocks long
+// Nodes are the direction of the run that reached a block and the block, since the next run has to turn away from it
+fn least_heat_loss(costs: &Grid<usize>, min_run: usize, max_run: usize) -> Result<usize, SolveError> {
+    let end = (costs.height() - 1, costs.width() - 1);
+    let min_cost = costs.iter().map(|(_, cost)| *cost).min().unwrap();
+
+    // Runs turn, so they cannot continue along or reverse the previous one
+    let runs = |&(path_limit, row_num, col_num): &(Direction, usize, usize)| {
+        let mut neighbors = Vec::new();
+        for run_dir in [path_limit.turn_left(), path_limit.turn_right()] {
+            // Follow the run until it leaves the grid, adding up the heat lost on the way
+            let mut path_cost = 0;
+            let mut run_pos = (row_num, col_num);
+            for offset in 1..(max_run + 1) {
+                let Some(next_pos) = costs.step(run_pos, run_dir.offset()) else {
+                    break;
+                };
+                run_pos = next_pos;
+                path_cost += costs[run_pos];
+                if offset >= min_run {
+                    neighbors.push(((run_dir, run_pos.0, run_pos.1), path_cost));
+                }
+            }
+        }
+        return neighbors;
+    };
+    // Every block left to cross loses at least the least heat of any block
+    let heuristic = |&(_, row_num, col_num): &(Direction, usize, usize)| ((end.0 - row_num) + (end.1 - col_num)) * min_cost;
+
+    // Search for shortest path with A*, starting as if arrived moving up so the first run is horizontal
+    let paths = search::astar((Direction::Up, 0, 0), runs, heuristic, |&(_, row_num, col_num)| (row_num, col_num) == end);
+    let Some(end_node) = paths.goal() else {
+        return Err(SolveError::unsolvable(Day17::DAY, "no run of the crucible ends on the bottom-right block"));
+    };
+    for node in paths.path_to(end_node).unwrap().iter().skip(1) {
+        trace!("{:?}", node);
+    }
+    return Ok(paths.distance(end_node).unwrap());
+}
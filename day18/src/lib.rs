@@ -0,0 +1,220 @@
+//! Day 18: Lavaduct Lagoon
+//!
+//! Measures the lagoon dug out by following a dig plan of directions and distances
+
+#![warn(missing_docs)]
+
+use std::collections::VecDeque;
+use common::{example, example::Example, parse, Answer, Direction, Overflow, Point, ParseError, Solution, SolveError};
+
+/// Solution for day 18, for use with the `aoc` runner
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+    const EXAMPLES: &'static [Example] = &[example!("example")];
+    type Input = Vec<DigInstruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(plan: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(plan).into());
+    }
+
+    fn part2(plan: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(plan)?.into());
+    }
+}
+
+/// One step of the dig plan
+pub struct DigInstruction {
+    /// Direction to dig, written as `U`, `D`, `L` or `R`
+    pub dir: Direction,
+    /// Meters to dig in that direction
+    pub step_count: usize,
+    /// Direction hidden in the last digit of the color code, 0 to 3 for `R`, `D`, `L` and `U`
+    pub color_dir: Direction,
+    /// Distance hidden in the first 5 hexadecimal digits of the color code
+    pub color_step_count: i64
+}
+
+/// Reads one `R 6 (#70c710)` instruction per line, decoding the instruction hidden in the color code
+/// Every instruction must dig at least 1 meter, so that the plan outlines a lagoon
+pub fn parse(input: &str) -> Result<Vec<DigInstruction>, ParseError> {
+    let lines = parse::lines(Day18::DAY, input);
+    if lines.is_empty() {
+        return Err(parse::end_of_input(Day18::DAY, input, "a dig instruction such as 'R 6 (#70c710)'"));
+    }
+    let mut instructions = Vec::new();
+    for ln in lines {
+        let (dir, rest) = ln.split_once(" ")?;
+        let (step_count_str, color_str) = rest.split_once(" ")?;
+        let dir = Direction::from_letter(dir.char_of("UDLR")?).unwrap();
+        let step_count: usize = step_count_str.parse()?;
+        if step_count == 0 {
+            return Err(step_count_str.error("at least 1 meter to dig"));
+        }
+        let color = color_str.strip_prefix("(#")?.strip_suffix(")")?; // Cut out the 6 hexadecimal digits
+        if color.len() != 6 {
+            return Err(color.error("6 hexadecimal digits"));
+        }
+        let (color_step_count_str, color_dir_str) = (color.slice(0, 5), color.slice(5, 6));
+        color_step_count_str.check_chars(|c| c.is_ascii_hexdigit(), "a hexadecimal digit")?;
+        let color_step_count = i64::from_str_radix(color_step_count_str.as_str(), 16).unwrap();
+        let color_dir = Direction::from_digit(color_dir_str.char_of("0123")?).unwrap();
+        instructions.push(DigInstruction { dir: dir, step_count: step_count, color_dir: color_dir, color_step_count: color_step_count });
+    }
+    return Ok(instructions);
+}
+
+/// Cubic meters of lava the lagoon holds, by flood filling the dug outline
+pub fn part1(plan: &Vec<DigInstruction>) -> usize {
+    // Draw boundary in dynamic grid
+    let mut num_rows = 4;
+    let mut num_cols = 4;
+    let mut grid = vec![vec!['.'; num_cols]; num_rows];
+    let mut curr_row = 0;
+    let mut curr_col = 0;
+    for DigInstruction { dir, step_count, .. } in plan {
+        let step_count = *step_count;
+        if *dir == Direction::Up {
+            // Expand upwards if necessary
+            while curr_row < step_count {
+                grid.splice(0..0, vec![vec!['.'; num_cols]; num_rows]);
+                curr_row += num_rows;
+                num_rows *= 2;
+            }
+            for _ in 0..step_count {
+                grid[curr_row][curr_col] = '#';
+                curr_row -= 1;
+            }
+        }
+        else if *dir == Direction::Down {
+            // Expand downwards if necessary
+            while curr_row + step_count >= num_rows {
+                grid.extend_from_slice(&vec![vec!['.'; num_cols]; num_rows]);
+                num_rows *= 2;
+            }
+            for _ in 0..step_count {
+                grid[curr_row][curr_col] = '#';
+                curr_row += 1;
+            }
+        }
+        else if *dir == Direction::Left {
+            // Expand leftwards if necessary
+            while curr_col < step_count {
+                for row_num in 0..num_rows {
+                    grid[row_num].splice(0..0, vec!['.'; num_cols]);
+                }
+                curr_col += num_cols;
+                num_cols *= 2;
+            }
+            for _ in 0..step_count {
+                grid[curr_row][curr_col] = '#';
+                curr_col -= 1;
+            }
+        }
+        else if *dir == Direction::Right {
+            // Expand rightwards if necessary
+            while curr_col + step_count >= num_cols {
+                for row_num in 0..num_rows {
+                    grid[row_num].extend_from_slice(&vec!['.'; num_cols]);
+                }
+                num_cols *= 2;
+            }
+            for _ in 0..step_count {
+                grid[curr_row][curr_col] = '#';
+                curr_col += 1;
+            }
+        }
+    }
+
+    // Perform BFS to fill interior
+    let mut visited = vec![vec![false; num_cols]; num_rows];
+    for row_num in 0..num_rows {
+        for col_num in 0..num_cols {
+            if grid[row_num][col_num] == '#' {
+                visited[row_num][col_num] = true;
+            }
+        }
+    }
+    let start_row = grid.iter().position(|row| row.contains(&'#')).unwrap(); // Find row with top-left corner
+    let start_col = grid[start_row].iter().position(|ch| *ch == '#').unwrap(); // Find column for top-left corner
+    
+    let mut bfs_queue = VecDeque::new();
+    bfs_queue.push_back((start_row + 1, start_col + 1)); // Find first empty space within top-left corner
+    while let Some((curr_row, curr_col)) = bfs_queue.pop_front() {
+        grid[curr_row][curr_col] = '#';
+        if curr_row > 0 && !visited[curr_row - 1][curr_col] {
+            bfs_queue.push_back((curr_row - 1, curr_col));
+            visited[curr_row - 1][curr_col] = true;
+        }
+        if curr_row + 1 < num_rows && !visited[curr_row + 1][curr_col] {
+            bfs_queue.push_back((curr_row + 1, curr_col));
+            visited[curr_row + 1][curr_col] = true;
+        }
+        if curr_col > 0 && !visited[curr_row][curr_col - 1] {
+            bfs_queue.push_back((curr_row, curr_col - 1));
+            visited[curr_row][curr_col - 1] = true;
+        }
+        if curr_col + 1 < num_cols && !visited[curr_row][curr_col + 1] {
+            bfs_queue.push_back((curr_row, curr_col + 1));
+            visited[curr_row][curr_col + 1] = true;
+        }
+    }
+
+    let mut num_filled = 0;
+    for row_num in 0..num_rows {
+        for col_num in 0..num_cols {
+            //print!("{}", grid[row_num][col_num]);
+            if grid[row_num][col_num] == '#' {
+                num_filled += 1;
+            }
+        }
+        //println!("");
+    }
+    return num_filled;
+}
+
+/// Cubic meters of lava the lagoon holds when the hexadecimal color codes give the real distances and directions
+/// The area is found with the shoelace formula, since the outline is too large to fill
+pub fn part2(plan: &Vec<DigInstruction>) -> Result<i64, Overflow> {
+    // Take the instructions hidden in the colors
+    let instructions: Vec<(Direction, i64)> = plan.iter().map(|i| (i.color_dir, i.color_step_count)).collect();
+
+    // Calculate vertices of boundary
+    // Adjust snapping based on direction of turn
+    let mut vertices = Vec::new();
+    let mut curr = Point::ORIGIN;
+    for (dir, step_count) in instructions.iter() {
+        vertices.push(curr);
+        curr += dir.unit() * *step_count;
+    }
+    //println!("{:?}", vertices);
+
+    // Calculate area, in 128 bits since the products of coordinates may not fit in 64
+    let mut area: i128 = 0;
+    for i in 0..vertices.len() {
+        let next_vertex = vertices[(i + 1) % vertices.len()];
+        area += 2 * ((vertices[i].row + next_vertex.row) as i128 * (next_vertex.col - vertices[i].col) as i128);
+
+        // Add padding
+        let line_len = (next_vertex - vertices[i]).manhattan();
+        area -= 2 * (line_len as i128 - 1);
+        
+        // Check if corner is concave or convex, add padding appropriately
+        let curr_dir = instructions[i].0;
+        let next_dir = instructions[(i + 1) % instructions.len()].0;
+        let is_convex = next_dir == curr_dir.turn_right();
+        if is_convex {
+            area -= 3;
+        }
+        else {
+            area -= 1;
+        }
+    }
+    area /= -4;    
+    return i64::try_from(area).map_err(|_| Overflow::new(Day18::DAY, "lagoon area"));
+}
@@ -0,0 +1,226 @@
+//! Day 19: Aplenty
+//!
+//! Sorts machine parts through workflows of rating conditions until they are accepted or rejected
+
+#![warn(missing_docs)]
+
+use std::collections::{HashMap, HashSet};
+use common::{debug, example, example::Example, parse, Answer, IntervalSet, Overflow, ParseError, Solution, SolveError};
+
+/// Solution for day 19, for use with the `aoc` runner
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+    const EXAMPLES: &'static [Example] = &[example!("example")];
+    type Input = System;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(system: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(system)?.into());
+    }
+
+    fn part2(system: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(system)?.into());
+    }
+}
+
+/// The condition of a workflow rule, such as `a<2006`
+#[derive(Debug)]
+pub struct RuleCondition {
+    /// Rating compared, one of `x`, `m`, `a` and `s`
+    pub rating: char,
+    /// Comparison, `'<'` or `'>'`
+    pub cmp_type: char,
+    /// Value the rating is compared against
+    pub cmp_value: i64
+}
+
+/// Workflows by name and the parts to sort through them
+pub struct System {
+    /// Rules of each workflow in order, as (condition, destination), where the last rule has no condition
+    pub rules_map: HashMap<String, Vec<(Option<RuleCondition>, String)>>,
+    /// Ratings of each part, as (x, m, a, s)
+    pub parts: Vec<(i64, i64, i64, i64)>
+}
+
+/// Reads the workflows, a blank line, then one `{x=787,m=2655,a=1222,s=2876}` part per line
+/// Every destination must name a workflow, `A` or `R`, and there must be an `in` workflow to start from
+pub fn parse(input_str: &str) -> Result<System, ParseError> {
+    let sections = parse::sections(Day19::DAY, input_str);
+    if sections.len() < 2 {
+        return Err(parse::end_of_input(Day19::DAY, input_str, "a list of workflows and a list of parts"));
+    }
+
+    let mut rules_map = HashMap::new();
+    let mut dest_spans = Vec::new();
+    for rl in &sections[0] {
+        let (rule_name, rule_list_str) = rl.strip_suffix("}")?.split_once("{")?;
+        let mut rule_list = Vec::new();
+        for r_str in rule_list_str.split(",") {
+            if let Ok((rule_condition_str, dest)) = r_str.split_once(":") {
+                if rule_condition_str.len() < 3 {
+                    return Err(rule_condition_str.error("a condition such as 'a<2006'"));
+                }
+                let rating = rule_condition_str.slice(0, 1).char_of("xmas")?;
+                let cmp_type = rule_condition_str.slice(1, 2).char_of("<>")?;
+                let cmp_value = rule_condition_str.slice(2, rule_condition_str.len()).parse::<i64>()?;
+
+                let rule_condition = RuleCondition { rating: rating, cmp_type: cmp_type, cmp_value: cmp_value};
+                rule_list.push((Some(rule_condition), String::from(dest.as_str())));
+                dest_spans.push(dest);
+            }
+            else {
+                rule_list.push((None, String::from(r_str.as_str())));
+                dest_spans.push(r_str);
+            }
+        }
+        rules_map.insert(String::from(rule_name.as_str()), rule_list);
+    }
+
+    // Every rule has to lead somewhere, starting from "in"
+    if !rules_map.contains_key("in") {
+        return Err(sections[0][0].error("a workflow named 'in'"));
+    }
+    for dest in dest_spans {
+        if dest.as_str() != "A" && dest.as_str() != "R" && !rules_map.contains_key(dest.as_str()) {
+            return Err(dest.error("'A', 'R' or a workflow name"));
+        }
+    }
+
+    let mut parts = Vec::new();
+    for pl in &sections[1] {
+        let rating_str_list = pl.strip_prefix("{")?.strip_suffix("}")?.split(",");
+        if rating_str_list.len() != 4 {
+            return Err(pl.error("4 ratings"));
+        }
+        let x_rating: i64 = rating_str_list[0].strip_prefix("x=")?.parse()?;
+        let m_rating: i64 = rating_str_list[1].strip_prefix("m=")?.parse()?;
+        let a_rating: i64 = rating_str_list[2].strip_prefix("a=")?.parse()?;
+        let s_rating: i64 = rating_str_list[3].strip_prefix("s=")?.parse()?;
+        parts.push((x_rating, m_rating, a_rating, s_rating));
+    }
+    return Ok(System { rules_map: rules_map, parts: parts });
+}
+
+/// Sums all the ratings of the accepted parts
+/// Fails if a part comes back to a workflow it has already been through, as it would then never be sorted
+pub fn part1(system: &System) -> Result<i64, SolveError> {
+    let rules_map = &system.rules_map;
+    let mut accepted_parts = Vec::new();
+    for p in &system.parts {
+        let mut curr_rule = "in";
+        let mut visited_rules = HashSet::new();
+        while (curr_rule != "A") && (curr_rule != "R") {
+            if !visited_rules.insert(curr_rule) {
+                let reason = format!("part {{x={},m={},a={},s={}}} comes back to workflow {}", p.0, p.1, p.2, p.3, curr_rule);
+                return Err(SolveError::unsolvable(Day19::DAY, &reason));
+            }
+            let rule_list = rules_map.get(curr_rule).unwrap();
+            for (condition, dest) in rule_list {
+                if let Some(RuleCondition { rating, cmp_type, cmp_value}) = condition {
+                    let cmp_rating = match rating {
+                        'x' => p.0,
+                        'm' => p.1,
+                        'a' => p.2,
+                        's' => p.3,
+                        _ => unreachable!()
+                    };
+                    let fulfills_condition = match cmp_type {
+                        '>' => cmp_rating > *cmp_value,
+                        '<' => cmp_rating < *cmp_value,
+                        _ => unreachable!()
+                    };
+                    if fulfills_condition {
+                        curr_rule = dest;
+                        break;
+                    }
+                }
+                else {
+                    curr_rule = dest;
+                    break;
+                }
+            }
+        }
+        if curr_rule == "A" {
+            accepted_parts.push(*p);
+        }
+    }
+    
+    let mut subtotal: i64 = 0;
+    for p in accepted_parts {
+        subtotal = [p.0, p.1, p.2, p.3].into_iter().try_fold(subtotal, |total, rating| total.checked_add(rating))
+                                                     .ok_or_else(|| Overflow::new(Day19::DAY, "sum of ratings"))?;
+    }
+    return Ok(subtotal);
+}
+
+/// Counts the combinations of ratings from 1 to 4000 that would be accepted, by splitting rating ranges along the conditions
+/// Fails if some ratings lead back to a workflow they have already been through
+pub fn part2(system: &System) -> Result<i64, SolveError> {
+    const X: usize = 0;
+    const M: usize = 1;
+    const A: usize = 2;
+    const S: usize = 3;
+
+    let rules_map = &system.rules_map;
+
+    // Determine ranges of values that are accepted
+    // Each rule splits the rating it checks into the numbers that pass on to its destination and the ones left for
+    // the next rule
+    // Each entry keeps the workflows its ratings went through, since the ratings would loop if they came back to one
+    let mut accepted_ranges = Vec::new();
+    let mut range_stack: Vec<(Vec<IntervalSet>, &str, Vec<&str>)> = vec![(vec![IntervalSet::from(1..=4000); 4], "in", Vec::new()); 1];
+    while let Some((mut p_range, curr_rule, mut visited_rules)) = range_stack.pop() {
+        if curr_rule == "A" {
+            accepted_ranges.push(p_range);
+        }
+        else if curr_rule != "R" {
+            if visited_rules.contains(&curr_rule) {
+                return Err(SolveError::unsolvable(Day19::DAY, &format!("some ratings come back to workflow {}", curr_rule)));
+            }
+            visited_rules.push(curr_rule);
+            let rule_list = rules_map.get(curr_rule).unwrap();
+            for (condition, dest) in rule_list {
+                if let Some(RuleCondition { rating, cmp_type, cmp_value}) = condition {
+                    let rating_idx = match rating {
+                        'x' => X,
+                        'm' => M,
+                        'a' => A,
+                        's' => S,
+                        _ => unreachable!()
+                    };
+
+                    let mut new_entry = p_range.clone();
+                    if *cmp_type == '>' {
+                        (p_range[rating_idx], new_entry[rating_idx]) = p_range[rating_idx].split_at(cmp_value + 1);
+                    }
+                    else {
+                        (new_entry[rating_idx], p_range[rating_idx]) = p_range[rating_idx].split_at(*cmp_value);
+                    }
+                    if !new_entry[rating_idx].is_empty() {
+                        range_stack.push((new_entry, dest, visited_rules.clone()));
+                    }
+                    if p_range[rating_idx].is_empty() {
+                        break;
+                    }
+                }
+                else {
+                    range_stack.push((p_range, dest, visited_rules));
+                    break;
+                }
+            }
+        }
+    }
+    debug!("{:?}", accepted_ranges);
+
+    // Calculate number of valid combinations
+    let mut subtotal = 0;
+    for entry in accepted_ranges {
+        subtotal += entry.iter().map(|r| r.len() as i64).product::<i64>();
+    }
+    return Ok(subtotal);
+}
@@ -0,0 +1,368 @@
+//! Day 20: Pulse Propagation
+//!
+//! Simulates pulses sent through a network of flip-flop and conjunction modules each time a button is pressed
+
+#![warn(missing_docs)]
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use common::{debug, example, example::Example, params::{self, Param, Params}, parse, Answer, Overflow, ParseError, Solution, SolveError};
+use graph::Graph;
+
+/// Solution for day 20, for use with the `aoc` runner
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+    const PARAMS: &'static [Param] = &[
+        Param { name: "presses", description: "button presses in part 1, default 1000", is_valid: params::parses_as::<usize> }
+    ];
+    const EXAMPLES: &'static [Example] = &[
+        example!("example-part1"),
+        example!("example-part1-b")
+    ];
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(network: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(network)?.into());
+    }
+
+    fn part2(network: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(network)?.into());
+    }
+
+    fn part1_with(network: &Self::Input, params: &Params) -> Result<Answer, SolveError> {
+        return Ok(pulse_product(network, params.get_or("presses", NUM_PRESSES))?.into());
+    }
+}
+
+/// Button presses in part 1
+pub const NUM_PRESSES: usize = 1000;
+
+/// Most button presses part 2 tries before giving up on a subgraph cycling
+pub const MAX_CYCLE_LENGTH: u64 = 10000;
+
+/// A pulse, and the state a module sends pulses in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Signal {
+    /// Low pulse, or a flip-flop that is off
+    Low,
+    /// High pulse, or a flip-flop that is on
+    High
+}
+
+/// A module and the names of the modules it sends pulses to
+#[derive(Clone, Debug)]
+pub enum PulseModule {
+    /// The button, which sends a low pulse to the broadcaster when pressed
+    Button(Vec<String>),
+    /// The broadcaster, which repeats every pulse it receives
+    Broadcast(Vec<String>),
+    /// A flip-flop (`%`), which toggles and sends its new state on each low pulse, and ignores high pulses
+    FlipFlop(Vec<String>),
+    /// A conjunction (`&`), which sends a low pulse once the latest pulses from all its inputs are high
+    /// The map holds its inputs
+    Conjunction(HashMap<String, Signal>, Vec<String>)
+}
+
+/// Module connections plus the initial module states and conjunction memories, with the button wired to the broadcaster
+#[derive(Clone, Debug)]
+pub struct Network {
+    /// Each module by name
+    pub connections_map: HashMap<String, PulseModule>,
+    /// State of each module, which is the pulse it sends next
+    pub state_map: HashMap<String, Signal>,
+    /// Latest pulse from each input of each conjunction module
+    pub conj_mem_map: HashMap<String, HashMap<String, Signal>>
+}
+
+/// Reads one `%a -> b, c` module per line, which must include the `broadcaster`
+/// A button module wired to the broadcaster is added
+pub fn parse(input: &str) -> Result<Network, ParseError> {
+    let mut parsed_lines = Vec::new();
+    for ln in parse::lines(Day20::DAY, input) {
+        let (module_str, output_list_str) = ln.split_once(" -> ")?;
+        if !module_str.as_str().starts_with(['%', '&']) && module_str.as_str() != "broadcaster" {
+            return Err(module_str.error("'%', '&' or 'broadcaster'"));
+        }
+        let output_list: Vec<&str> = output_list_str.split(", ").iter().map(|s| s.as_str()).collect();
+        parsed_lines.push((module_str.as_str(), output_list));
+    }
+    if !parsed_lines.iter().any(|(module_str, _)| *module_str == "broadcaster") {
+        return Err(parse::end_of_input(Day20::DAY, input, "a 'broadcaster' module"));
+    }
+
+    // Set up connections
+    let mut connections_map: HashMap<String, PulseModule> = HashMap::new();
+    let mut conj_mem_map: HashMap<String, HashMap<String, Signal>> = HashMap::new();
+    for (module_str, output_list) in &parsed_lines {
+        let output_list = output_list.iter().map(|s| String::from(*s)).collect();
+        if module_str.starts_with('%') {
+            connections_map.insert(String::from(&module_str[1..]), PulseModule::FlipFlop(output_list));
+        }
+        else if module_str.starts_with('&') {
+            connections_map.insert(String::from(&module_str[1..]), PulseModule::Conjunction(HashMap::new(), output_list));
+            conj_mem_map.insert(String::from(&module_str[1..]), HashMap::new());
+        }
+        else {
+            connections_map.insert(String::from(*module_str), PulseModule::Broadcast(output_list));
+        }
+    }
+    // Set up dummy modules for names that appear in output but not input
+    /*
+    for (module_str, output_list) in &parsed_lines {
+        for dest_module in output_list {
+            if connections_map.get(*dest_module).is_none() {
+                connections_map.insert(String::from(*dest_module),PulseModule::Button(Vec::new()));
+            }
+        }
+    }
+    */
+    // Determine all inputs for conjunction modules
+    for (module_str, output_list) in &parsed_lines {
+        for dest_module in output_list {
+            if let Some(PulseModule::Conjunction(inputs, _)) = connections_map.get_mut(*dest_module) {
+                inputs.insert(String::from(module_str.trim_start_matches(['%', '&'])), Signal::Low);
+            }
+            if let Some(conj_mem) = conj_mem_map.get_mut(*dest_module) {
+                conj_mem.insert(String::from(module_str.trim_start_matches(['%', '&'])), Signal::Low);
+            }
+        }
+    }
+
+    // Set up module states
+    let mut state_map = HashMap::new();
+    for (module_str, _) in &parsed_lines {
+        if module_str.starts_with('%') {
+            state_map.insert(String::from(&module_str[1..]), Signal::Low);
+        }
+        else if module_str.starts_with('&') {
+            state_map.insert(String::from(&module_str[1..]), Signal::High);
+        }
+        else {
+            state_map.insert(String::from(*module_str), Signal::Low);
+        }
+    }
+    // Set up dummy modules for names that appear in output but not input
+    /*
+    for (module_str, output_list) in &parsed_lines {
+        for dest_module in output_list {
+            state_map.get(*dest_module).get_or_insert(&Signal::Low);
+        }
+    }
+    */
+
+    // Set up hard-coded button module
+    connections_map.insert(String::from("button"), PulseModule::Button(vec![String::from("broadcaster")]));
+    state_map.insert(String::from("button"), Signal::Low);
+
+    return Ok(Network { connections_map: connections_map, state_map: state_map, conj_mem_map: conj_mem_map });
+}
+
+/// Multiplies the numbers of low and high pulses sent over 1000 button presses
+pub fn part1(network: &Network) -> Result<u64, Overflow> {
+    return pulse_product(network, NUM_PRESSES);
+}
+
+/// Product of the numbers of low and high pulses sent over the given number of button presses
+pub fn pulse_product(network: &Network, num_presses: usize) -> Result<u64, Overflow> {
+    let overflow = || Overflow::new(Day20::DAY, "pulse product");
+    let connections_map = &network.connections_map;
+    let mut state_map = network.state_map.clone();
+    let mut conj_mem_map = network.conj_mem_map.clone();
+
+    // Process messages
+    let mut total_low_signals: u64 = 0;
+    let mut total_high_signals: u64 = 0;
+
+    for _ in 0..num_presses {
+        let (num_low_signals, num_high_signals) = iterate_subgraph(connections_map, &mut state_map, &mut conj_mem_map,&HashSet::new());
+        total_low_signals = total_low_signals.checked_add(num_low_signals).ok_or_else(overflow)?;
+        total_high_signals = total_high_signals.checked_add(num_high_signals).ok_or_else(overflow)?;
+    }
+
+    debug!("total_low_signals: {}", total_low_signals);
+    debug!("total_high_signals: {}", total_high_signals);
+    return total_low_signals.checked_mul(total_high_signals).ok_or_else(overflow);
+}
+
+/// Counts the button presses until `rx` receives a low pulse, as the product of the cycle lengths of the
+/// subgraphs feeding it
+/// Assumes the shape of the puzzle inputs: `rx` is fed by one conjunction, which is fed by one subgraph per broadcaster output,
+/// each a counter with a prime cycle length
+/// Fails if no module sends to `rx`, a broadcaster output has no path to it, or a subgraph does not cycle within
+/// `MAX_CYCLE_LENGTH` presses
+pub fn part2(network: &Network) -> Result<u64, SolveError> {
+    let connections_map = &network.connections_map;
+    let state_map = &network.state_map;
+    let conj_mem_map = &network.conj_mem_map;
+
+    // Set up subtrees, count number of iterations needed to complete cycle
+    let subtrees = find_subtrees(connections_map)?;
+    let mut cycle_lengths = Vec::new();
+
+    for (i, s) in subtrees.iter().enumerate() {
+        let subtree_nodes = Vec::from_iter(s.iter().cloned());
+        let subtree_node_set = HashSet::from_iter(subtree_nodes.clone());
+        let mut subtree_state: HashMap<String, Signal> = subtree_nodes.iter().filter_map(|m| state_map.get(m).map(|state| ((*m).clone(), *state))).collect();
+        let mut subtree_conj_mem = conj_mem_map.clone();
+        let mut swap_indices: Vec<usize> = Vec::new();
+
+        iterate_subgraph(connections_map, &mut subtree_state,&mut subtree_conj_mem, &subtree_node_set);
+        let init_state = subtree_state.clone();
+        let mut prev_output_state = *subtree_state.get(s.last().unwrap()).unwrap();
+        //println!("init_state: {:?}", init_state);
+        let mut cycle_length = None;
+        for button_press_counter in 1..=MAX_CYCLE_LENGTH {
+            iterate_subgraph(connections_map, &mut subtree_state,&mut subtree_conj_mem, &subtree_node_set);
+            let next_output_state = *subtree_state.get(s.last().unwrap()).unwrap();
+            if next_output_state != prev_output_state {
+                swap_indices.push(i);
+                prev_output_state = next_output_state;
+            }
+            if subtree_state == init_state {
+                debug!("Subtree {} cycle length: {}", i, button_press_counter);
+                cycle_length = Some(button_press_counter);
+                //println!("{:?}", swap_indices);
+                //println!("subtree_state: {:?}", subtree_state);
+                break;
+            }
+        }
+        match cycle_length {
+            Some(n) => cycle_lengths.push(n),
+            None => return Err(SolveError::unsolvable(Day20::DAY, &format!("the subgraph ending at {} does not cycle within {} presses", s.last().unwrap(), MAX_CYCLE_LENGTH)))
+        }
+    }
+    let product = cycle_lengths.into_iter().try_fold(1, |product: u64, n| product.checked_mul(n))
+                               .ok_or_else(|| Overflow::new(Day20::DAY, "product of cycle lengths"))?;
+    return Ok(product);
+}
+
+/// Names of the modules a module sends pulses to
+fn module_outputs(module: &PulseModule) -> &Vec<String> {
+    return match module {
+        PulseModule::Button(outputs) => outputs,
+        PulseModule::Broadcast(outputs) => outputs,
+        PulseModule::FlipFlop(outputs) => outputs,
+        PulseModule::Conjunction(_, outputs) => outputs
+    };
+}
+
+/// Modules connected to each broadcaster output once the button, the broadcaster and the conjunction that feeds rx
+/// are taken out of the network, each starting with the button and broadcaster and ending with the module that feeds
+/// that conjunction
+fn find_subtrees(connections_map: &HashMap<String, PulseModule>) -> Result<Vec<Vec<String>>, SolveError> {
+    let final_module = connections_map.iter().find(|(_, m)| module_outputs(m).iter().any(|o| o == "rx"))
+                                      .map(|(name, _)| name.clone())
+                                      .ok_or_else(|| SolveError::unsolvable(Day20::DAY, "no module sends to rx"))?;
+    let no_path = |start: &String| SolveError::unsolvable(Day20::DAY, &format!("no path from {start} to {final_module}"));
+    let mut module_graph: Graph<String, ()> = Graph::directed();
+    for (module_name, module) in connections_map.iter() {
+        if *module_name == "button" || *module_name == "broadcaster" || *module_name == final_module {
+            continue;
+        }
+        let source = module_graph.add_node(module_name.clone());
+        for output in module_outputs(module).iter().filter(|o| **o != final_module) {
+            let dest = module_graph.add_node(output.clone());
+            module_graph.add_edge(source, dest, ());
+        }
+    }
+    let components = module_graph.components();
+
+    let mut subtrees = Vec::new();
+    for start in module_outputs(connections_map.get("broadcaster").unwrap()) {
+        let component = module_graph.id(start).and_then(|id| components.iter().find(|c| c.contains(&id)))
+                                    .ok_or_else(|| no_path(start))?;
+        let mut subtree = vec![String::from("button"), String::from("broadcaster")];
+        subtree.extend(component.iter().map(|id| module_graph.label(*id).clone()));
+
+        // The last module's output is watched for the cycle
+        let last_module = subtree.iter().find(|m| connections_map.get(*m).is_some_and(|module| module_outputs(module).contains(&final_module)))
+                                 .cloned()
+                                 .ok_or_else(|| no_path(start))?;
+        subtree.retain(|m| *m != last_module);
+        subtree.push(last_module);
+        subtrees.push(subtree);
+    }
+    return Ok(subtrees);
+}
+
+/// Presses the button once and propagates the pulses until none are left, updating the module states and memories
+/// Only modules in `subgraph` receive pulses, unless it is empty
+/// Returns the numbers of low and high pulses sent
+pub fn iterate_subgraph(connections_map: &HashMap<String, PulseModule>, state_map: &mut HashMap<String, Signal>, conj_mem_map: &mut HashMap<String, HashMap<String, Signal>>, subgraph: &HashSet<String>) -> (u64, u64) {
+    let mut num_low_signals = 0;
+    let mut num_high_signals = 0;
+    let mut module_queue = VecDeque::new();
+    module_queue.push_back("button");
+    while let Some(source_module_name) = module_queue.pop_front() {
+        // Find modules to output signals to
+        let output_module_names = module_outputs(connections_map.get(source_module_name).unwrap());
+        
+        // Determine state of source module
+        let source_module_state = *state_map.get(source_module_name).unwrap();
+
+        // Change state in output modules
+        for dest_module_name in output_module_names {
+            if subgraph.is_empty() || subgraph.contains(dest_module_name.as_str()) {
+                // Process incoming signal
+                //println!("{:?}", state_map);
+                //println!("{} --{:?}-> {}", source_module_name, source_module_state, dest_module_name);
+                let mut state_changed_flag = false;
+                if let Some(dest_module_connections) = connections_map.get(dest_module_name) {
+                    match dest_module_connections {
+                        PulseModule::Button(_) => {},
+                        PulseModule::Broadcast(_) => {
+                            state_map.insert(String::from(dest_module_name), source_module_state);
+                            state_changed_flag = true;
+                        },
+                        PulseModule::FlipFlop(_) => {
+                            match source_module_state {
+                                Signal::Low => {
+                                    let dest_curr_state = state_map.get(dest_module_name).unwrap();
+                                    let dest_new_state = match *dest_curr_state {
+                                        Signal::Low => Signal::High,
+                                        Signal::High => Signal::Low,
+                                    };
+                                    state_map.insert(String::from(dest_module_name), dest_new_state);
+                                    state_changed_flag = true;
+                                },
+                                Signal::High => {}
+                            }
+                        },
+                        PulseModule::Conjunction(_, _) => {
+                            // Update conjunction module memory
+                            let module_mem = conj_mem_map.get_mut(dest_module_name).unwrap();
+                            if *module_mem.get(source_module_name).unwrap() != source_module_state {
+                                module_mem.insert(String::from(source_module_name), source_module_state);
+                            }
+
+                            // Update module state based on updated memory
+                            let dest_new_state = if module_mem.values().all(|sgl| *sgl == Signal::High) {
+                                Signal::Low
+                            }
+                            else {
+                                Signal::High
+                            };
+                            
+                            state_map.insert(String::from(dest_module_name), dest_new_state);
+                            state_changed_flag = true;
+                        },
+                    }
+                }
+                // Add destination to processing queue
+                match source_module_state {
+                    Signal::Low => { num_low_signals += 1 },
+                    Signal::High => { num_high_signals += 1 }
+                }
+                if state_changed_flag {
+                    module_queue.push_back(dest_module_name);
+                }
+            }
+        }
+    }
+    return (num_low_signals, num_high_signals);
+}
\ No newline at end of file
@@ -0,0 +1,196 @@
+//! Day 21: Step Counter
+//!
+//! Counts the garden plots an elf can reach in an exact number of steps
+
+#![warn(missing_docs)]
+
+use common::{debug, example, example::Example, params::{self, Param, Params}, parse, search, trace, Answer, Grid, Overflow, ParseError, Solution, SolveError};
+
+/// Solution for day 21, for use with the `aoc` runner
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u32 = 21;
+    const PARAMS: &'static [Param] = &[
+        Param { name: "steps", description: "steps taken in part 1, default 64", is_valid: params::parses_as::<usize> },
+        Param { name: "infinite_steps", description: "steps taken on the infinite map in part 2, which must be a whole number of map widths plus half a width, default 26501365", is_valid: params::parses_as::<usize> }
+    ];
+    const EXAMPLES: &'static [Example] = &[example!("example-part1")];
+    type Input = Garden;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(garden: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(garden).into());
+    }
+
+    fn part2(garden: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(garden)?.into());
+    }
+
+    fn part1_with(garden: &Self::Input, params: &Params) -> Result<Answer, SolveError> {
+        return Ok(reachable_after_steps(garden, params.get_or("steps", NUM_STEPS)).into());
+    }
+
+    fn part2_with(garden: &Self::Input, params: &Params) -> Result<Answer, SolveError> {
+        return Ok(infinite_reachable_after_steps(garden, params.get_or("infinite_steps", NUM_INFINITE_STEPS))?.into());
+    }
+}
+
+/// Steps taken in part 1
+pub const NUM_STEPS: usize = 64;
+/// Steps taken on the infinite map in part 2
+pub const NUM_INFINITE_STEPS: usize = 26501365;
+
+/// The map of garden plots and rocks, and where the elf starts
+pub struct Garden {
+    /// Every tile of the map, with the start tile counted as a garden plot
+    pub tiles: Grid<Tile>,
+    /// Row and column of the start tile `S`
+    pub start: (usize, usize)
+}
+
+/// A tile of the map
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
+    /// A garden plot `.`, which can be walked on
+    Garden,
+    /// A rock `#`, which cannot
+    Rock
+}
+
+/// Reads the map, checked to form a square grid with a single start tile `S`
+pub fn parse(input: &str) -> Result<Garden, ParseError> {
+    let lines = parse::lines(Day21::DAY, input);
+    let grid = parse::char_grid(Day21::DAY, input, ".#S")?;
+    if grid.height() != grid.width() {
+        return Err(parse::end_of_input(Day21::DAY, input, &format!("{} rows to make a square map", grid.width())));
+    }
+    let start_tiles: Vec<(usize, usize)> = grid.iter().filter(|(_, c)| **c == 'S').map(|(pos, _)| pos).collect();
+    if start_tiles.is_empty() {
+        return Err(parse::end_of_input(Day21::DAY, input, "a start tile 'S'"));
+    }
+    if start_tiles.len() > 1 {
+        let (row, col) = start_tiles[1];
+        return Err(lines[row].slice(col, col + 1).error("a single start tile 'S'"));
+    }
+    let tiles = grid.map(|c| if *c == '#' { Tile::Rock } else { Tile::Garden });
+    return Ok(Garden { tiles: tiles, start: start_tiles[0] });
+}
+
+#[allow(dead_code)]
+fn print_map(map: &Grid<Tile>, reachable: &Grid<bool>) {
+    let map_chars = Grid::from_fn(map.width(), map.height(), |pos| {
+        if reachable[pos] {
+            return 'O';
+        }
+        else if map[pos] == Tile::Garden {
+            return '.';
+        }
+        return '#';
+    });
+    trace!("{map_chars}");
+}
+
+/// Counts the garden plots reachable in exactly 64 steps
+pub fn part1(garden: &Garden) -> usize {
+    return reachable_after_steps(garden, NUM_STEPS);
+}
+
+/// Garden plots reachable in exactly the given number of steps
+pub fn reachable_after_steps(garden: &Garden, num_steps: usize) -> usize {
+    let map = &garden.tiles;
+
+    // Iterate from reachable positions
+    let mut reachable = map.map(|_| false);
+    reachable[garden.start] = true;
+    for _ in 0..num_steps {
+        reachable = Grid::from_fn(map.width(), map.height(), |pos| {
+            return map[pos] == Tile::Garden && map.neighbors4(pos).any(|n| reachable[n]);
+        });
+    }
+
+    // Count reachable positions
+    //print_map(map, &reachable);
+    return reachable.iter().filter(|(_, r)| **r).count();
+}
+
+/// Counts the garden plots reachable in exactly 26501365 steps on a map that repeats infinitely in every direction
+pub fn part2(garden: &Garden) -> Result<u64, SolveError> {
+    return infinite_reachable_after_steps(garden, NUM_INFINITE_STEPS);
+}
+
+/// Garden plots reachable in exactly the given number of steps on the infinitely repeating map
+/// Relies on the real inputs' clear middle row and column, and fails unless the steps end at the edge of a map copy
+pub fn infinite_reachable_after_steps(garden: &Garden, num_steps: usize) -> Result<u64, SolveError> {
+    let tile_map = &garden.tiles;
+    let width = tile_map.width();
+    let height = tile_map.height();
+    if num_steps < height / 2 || !(num_steps - height / 2).is_multiple_of(height) {
+        let reason = format!("{num_steps} steps is not a whole number of map widths plus half a width, {} plus a multiple of {height}", height / 2);
+        return Err(SolveError::unsolvable(Day21::DAY, &reason));
+    }
+    // Without the clear middle row and column, the corner copies can hold more plots than the full copies they are
+    // cut from
+    let unsupported_map = || SolveError::unsolvable(Day21::DAY, "the map has no clear middle row and column, so the plots in the corners do not add up");
+
+    // Simulate 5x5 to find reachable tile count on each tile variation
+    let sim_tile_map = Grid::from_fn(5 * width, 5 * height, |(row, col)| tile_map[(row % height, col % width)]); // Simulate a 5x5 in order to get all odd/even and corner variations
+    let sim_start = (sim_tile_map.height() / 2, sim_tile_map.width() / 2);
+    let sim_paths = search::bfs(sim_start, |pos| sim_tile_map.neighbors4(*pos).filter(|next| sim_tile_map[*next] == Tile::Garden).collect::<Vec<_>>());
+
+    let num_sim_steps = 2 * width + width / 2;
+    let reachable = Grid::from_fn(sim_tile_map.width(), sim_tile_map.height(), |pos| {
+        return sim_paths.distance(&pos).is_some_and(|d| d <= num_sim_steps && (d + num_sim_steps).is_multiple_of(2));
+    });
+    //print_map(&sim_tile_map, &reachable);
+
+    let full_odd_reach = count_reachable(&reachable, 2 * width, 3 * width, 2 * height, 3 * height);
+    let full_even_reach = count_reachable(&reachable, 3 * width, 4 * width, 2 * height, 3 * height);
+
+    let odd_corner_top_reach = count_reachable(&reachable, 2 * width, 3 * width, 0, height);
+    let odd_corner_bottom_reach = count_reachable(&reachable, 2 * width, 3 * width, 4 * height, 5 * height);
+    let even_corner_tl_reach = count_reachable(&reachable, width, 2 * width, 0, height);
+    let even_corner_tr_reach = count_reachable(&reachable, 3 * width, 4 * width, 0, height);
+    let even_corner_bl_reach = count_reachable(&reachable, width, 2 * width, 4 * height, 5 * height);
+    let even_corner_br_reach = count_reachable(&reachable, 3 * width, 4 * width, 4 * height, 5 * height);
+    let even_corner_reach = even_corner_tl_reach + even_corner_tr_reach + even_corner_bl_reach + even_corner_br_reach;
+    let odd_corner_reach = (2 * full_odd_reach).checked_sub(odd_corner_top_reach + odd_corner_bottom_reach).ok_or_else(unsupported_map)?;
+    
+    debug!("full_odd_reach: {full_odd_reach}, full_even_reach: {full_even_reach}");
+    debug!("odd_corner_top_reach: {odd_corner_top_reach}, odd_corner_bottom_reach: {odd_corner_bottom_reach}");
+    debug!("even_corner_tl_reach: {even_corner_tl_reach}, even_corner_tr_reach: {even_corner_tr_reach}, even_corner_bl_reach: {even_corner_bl_reach}, even_corner_br_reach: {even_corner_br_reach}");
+
+    // Compute total reachable tiles
+    let chunk_radius: u64 = ((num_steps - height / 2) / height).try_into().unwrap();
+    debug!("chunk_radius: {chunk_radius}");
+    debug!("full_even_reach: {full_even_reach}, full_odd_reach: {full_odd_reach}, even_corner_reach: {even_corner_reach}, odd_corner_reach: {odd_corner_reach}");
+    
+    // The chunk counts grow with the square of the steps, so large step counts can overflow
+    let overflow = || Overflow::new(Day21::DAY, "number of reachable garden plots");
+    let num_odd_corners = chunk_radius.checked_add(1).ok_or_else(overflow)?; // Odd corners are removed
+    let num_even_corners = chunk_radius;
+    let num_odd_chunks = num_odd_corners.checked_mul(num_odd_corners).ok_or_else(overflow)?;
+    let num_even_chunks = chunk_radius.checked_mul(chunk_radius).ok_or_else(overflow)?;
+
+    let added_terms = [num_even_chunks.checked_mul(full_even_reach), num_odd_chunks.checked_mul(full_odd_reach), num_even_corners.checked_mul(even_corner_reach)];
+    let added = added_terms.into_iter().try_fold(0, |total: u64, term| total.checked_add(term?)).ok_or_else(overflow)?;
+    let removed = num_odd_corners.checked_mul(odd_corner_reach).ok_or_else(overflow)?;
+    let total = added.checked_sub(removed).ok_or_else(unsupported_map)?;
+    debug!("Total: {}", total);
+    return Ok(total);
+}
+
+fn count_reachable(reachable: &Grid<bool>, start_x: usize, end_x: usize, start_y: usize, end_y: usize) -> u64 {
+    let mut num_reachable = 0;
+    for j in start_y..end_y {
+        for i in start_x..end_x {
+            if reachable[(j, i)] {
+                num_reachable += 1;
+            }
+        }
+    }
+    return num_reachable;
+}
@@ -0,0 +1,257 @@
+//! Day 23: A Long Walk
+//!
+//! Finds the longest hike through a trail map that never steps on the same tile twice
+
+#![warn(missing_docs)]
+
+use std::collections::{HashMap, HashSet};
+use common::{example, example::Example, grid::NEIGHBORS4, log, parse, search, trace, Answer, Grid, ParseError, Solution, SolveError};
+use graph::{Graph, NodeId};
+
+/// Solution for day 23, for use with the `aoc` runner
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u32 = 23;
+    const EXAMPLES: &'static [Example] = &[example!("example")];
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(grid)?.into());
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(grid).into());
+    }
+}
+
+
+/// Reads the trail map, which has to start and end with an open tile in its first and last rows
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    let grid = parse::char_grid(Day23::DAY, input, "#.^>v<")?;
+    let lines = parse::lines(Day23::DAY, input);
+    if !grid.row(0).contains(&'.') {
+        return Err(lines[0].error("an open tile '.' to start from"));
+    }
+    if !grid.row(grid.height() - 1).contains(&'.') {
+        return Err(lines[lines.len() - 1].error("an open tile '.' to end at"));
+    }
+    return Ok(grid);
+}
+
+struct DAGNode {
+    parents: HashSet<(usize, usize)>,
+    children: HashSet<(usize, usize)>
+}
+
+/// Length of the longest hike when slopes can only be walked downhill
+/// Fails unless the slopes make the trails a DAG that leads to the end, which they do in the puzzle inputs
+pub fn part1(grid: &Grid<char>) -> Result<usize, SolveError> {
+    // Build DAG
+    let mut dag_node_map: HashMap<(usize, usize), DAGNode> = HashMap::new();
+
+    //let mut visited = vec![vec![false; width]; height];
+    let mut visit_stack = Vec::new();
+
+    let start_col = grid.row(0).iter().position(|c| *c == '.').unwrap();
+    let end_col = grid.row(grid.height() - 1).iter().position(|c| *c == '.').unwrap();
+    //visited[0][start_col] = true;
+    visit_stack.push((0, start_col));
+    let start_dag_node = DAGNode { parents: HashSet::new(), children: HashSet::new()};
+    dag_node_map.insert((0, start_col), start_dag_node);
+
+    while visit_stack.len() > 0 {
+        let (curr_row, curr_col) = visit_stack.pop().unwrap();
+        let curr_node_parents = &dag_node_map.get(&(curr_row, curr_col)).unwrap().parents.clone(); // Inefficient, look at this later
+        
+        // Add current node as parent to all children (all outgoing connections which are not parent nodes)
+        let new_node_children: HashSet<(usize, usize)> = get_node_children(grid, (curr_row, curr_col)).difference(curr_node_parents).copied().collect();
+        for (child_row, child_col) in new_node_children.iter() {
+            match dag_node_map.get_mut(&(*child_row, *child_col)) {
+                Some(dag) => { // Add current node as parent
+                    dag.parents.insert((curr_row, curr_col));
+                },
+                None => { // Create new node and add current node as parent
+                    let new_dag_node = DAGNode { parents: HashSet::from([(curr_row, curr_col)]), children: HashSet::new() };
+                    dag_node_map.insert((*child_row, *child_col), new_dag_node);
+                    visit_stack.push((*child_row, *child_col));
+                }
+            }
+        }
+
+        dag_node_map.get_mut(&(curr_row, curr_col)).unwrap().children = new_node_children;
+    }
+
+    // The directions found above only hold if a hike can never step from a tile back onto a parent other than the
+    // one it came from, which happens where open trails form a ring
+    for (coords, dag_node) in dag_node_map.iter() {
+        if dag_node.parents.len() > 1 && get_node_children(grid, *coords).iter().any(|n| dag_node.parents.contains(n)) {
+            return Err(SolveError::unsolvable(Day23::DAY, "the slopes let the trails loop back on themselves"));
+        }
+    }
+
+    // Find distances in topological order, taking each node once the distances from all of its parents are in
+    // Nodes on a loop never get there, since a parent of theirs waits on them
+    let mut num_waiting_parents: HashMap<(usize, usize), usize> = dag_node_map.iter().map(|(coords, n)| (*coords, n.parents.len())).collect();
+    let mut tentative_distances = HashMap::new();
+    tentative_distances.insert((0, start_col), 0);
+    let mut ready_nodes = vec![(0, start_col)];
+    while let Some(curr_coords) = ready_nodes.pop() {
+        let curr_distance = tentative_distances[&curr_coords];
+        for child_coords in dag_node_map[&curr_coords].children.iter() {
+            let child_distance = tentative_distances.entry(*child_coords).or_insert(0);
+            *child_distance = (*child_distance).max(curr_distance + 1);
+            let num_waiting = num_waiting_parents.get_mut(child_coords).unwrap();
+            *num_waiting -= 1;
+            if *num_waiting == 0 {
+                ready_nodes.push(*child_coords);
+            }
+        }
+    }
+    if num_waiting_parents.values().any(|n| *n > 0) {
+        return Err(SolveError::unsolvable(Day23::DAY, "the slopes let the trails loop back on themselves"));
+    }
+
+    return tentative_distances.get(&(grid.height() - 1, end_col)).copied()
+                              .ok_or_else(|| SolveError::unsolvable(Day23::DAY, "the end cannot be reached from the start"));
+}
+
+// Slope that can be walked onto from each direction of NEIGHBORS4
+const DOWNHILL_SLOPES: [char; 4] = ['^', 'v', '<', '>'];
+
+fn get_node_children(grid: &Grid<char>, coords: (usize, usize)) -> HashSet<(usize, usize)> {
+    let mut children = HashSet::new();
+    for (offset, slope) in NEIGHBORS4.iter().zip(DOWNHILL_SLOPES) {
+        if let Some(next) = grid.step(coords, *offset) {
+            if grid[next] == '.' || grid[next] == slope {
+                children.insert(next);
+            }
+        }
+    }
+    return children;
+}
+
+/// Length of the longest hike when slopes can be climbed like any other path
+/// The trails are reduced to a graph of junctions, and every path through it is tried
+pub fn part2(grid: &Grid<char>) -> usize {
+    // Build graph
+    let height = grid.height();
+    let start_col = grid.row(0).iter().position(|c| *c == '.').unwrap();
+    let end_col = grid.row(height - 1).iter().position(|c| *c == '.').unwrap();
+    let is_node = Grid::from_fn(grid.width(), height, |pos| grid[pos] != '#' && get_neighbors(grid, pos).len() != 2);
+    let mut graph = Graph::undirected();
+    for (node_coords, _) in is_node.iter().filter(|(_, n)| **n) {
+        graph.add_node(node_coords);
+    }
+    for node in graph.nodes() {
+        // Follow each outgoing path to the neighboring node, adding it from the node that comes first
+        let node_coords = *graph.label(node);
+        for nbr_coords in get_neighbors(grid, node_coords) {
+            if let Some((dest_coords, distance)) = follow_path(grid, &is_node, node_coords, nbr_coords) {
+                if node_coords < dest_coords {
+                    let dest = graph.id(&dest_coords).unwrap();
+                    graph.add_edge(node, dest, distance);
+                }
+            }
+        }
+    }
+    if log::enabled(log::Level::Trace) {
+        print_nodes(&graph);
+    }
+
+    // Iterate through all possible paths
+    let mut tentative_max_length = 0;
+    let mut curr_path = Vec::new(); // Current path nodes in order
+    let mut curr_path_edges = HashMap::new();   // Key is path node, value is prev node and distance
+    let mut explore_stack = Vec::new(); // Edges in the format of (source node, dest node, distance)
+
+    let start_node = graph.id(&(0, start_col)).unwrap();
+    let end_node = graph.id(&(height - 1, end_col)).unwrap();
+    curr_path.push(start_node);
+    curr_path_edges.insert(start_node, (start_node, 0));
+    for (n, dist) in graph.neighbors(start_node) {
+        explore_stack.push((start_node, n, dist));
+    }
+    while explore_stack.len() > 0 {
+        let (prev_node, curr_node, dist) = explore_stack.pop().unwrap();
+        while prev_node != *curr_path.last().unwrap() {
+            let last_node = curr_path.pop().unwrap();
+            curr_path_edges.remove(&last_node);
+        }
+        //println!("Exploring {:?} from {:?}", curr_node, prev_node);
+        //println!("{:?}", explore_stack);
+        //println!("{:?}", curr_path);
+        if !curr_path_edges.contains_key(&curr_node) {
+            // If path completed, calculate total length
+            if curr_node == end_node {
+                curr_path_edges.insert(curr_node, (prev_node, dist));
+                let path_length = curr_path_edges.values().map(|(_, dist)| *dist).sum::<usize>();
+                //print_path(&graph, &curr_path_edges, end_node);
+                //println!("Length: {}", path_length);
+                if path_length > tentative_max_length {
+                    tentative_max_length = path_length;
+                }
+                curr_path_edges.remove(&curr_node);
+                continue;
+            }
+
+            // Add current node to path, continue exploring
+            let unvisited_neighbors: Vec<(NodeId, usize)> = graph.neighbors(curr_node)
+                                        .filter(|(n, _)| !curr_path_edges.contains_key(n))
+                                        .collect();
+            if unvisited_neighbors.len() > 0 { // Add next node's neighbors to explore stack
+                curr_path.push(curr_node);
+                curr_path_edges.insert(curr_node, (prev_node, dist));
+                for (next_node, next_dist) in unvisited_neighbors {
+                    explore_stack.push((curr_node, next_node, next_dist));
+                }
+            }
+        }
+    }
+    return tentative_max_length;
+}
+
+// The node at the other end of the path leaving a node through its neighbor, and the length of the path
+// Tiles with exactly 1 neighbor or 3+ neighbors are nodes, and the tiles between them have one way in and one way out
+fn follow_path(grid: &Grid<char>, is_node: &Grid<bool>, node_coords: (usize, usize), nbr_coords: (usize, usize)) -> Option<((usize, usize), usize)> {
+    let path = search::bfs(nbr_coords, |ptr_coords| {
+        if is_node[*ptr_coords] {
+            return Vec::new();
+        }
+        return get_neighbors(grid, *ptr_coords).into_iter().filter(|n| *n != node_coords).collect();
+    });
+    return path.distances().find(|(ptr_coords, _)| is_node[**ptr_coords]).map(|(ptr_coords, distance)| (*ptr_coords, distance + 1));
+}
+
+fn get_neighbors(grid: &Grid<char>, tile_coords: (usize, usize)) -> Vec<(usize, usize)> {
+    return grid.neighbors4(tile_coords).filter(|n| grid[*n] != '#').collect();
+}
+
+fn print_nodes(graph: &Graph<(usize, usize), usize>) {
+    trace!("[{} nodes]", graph.num_nodes());
+    for node in graph.nodes() {
+        let neighbors: Vec<((usize, usize), usize)> = graph.neighbors(node).map(|(n, dist)| (*graph.label(n), dist)).collect();
+        trace!("{:?} - neighbors: {:?}", graph.label(node), neighbors);
+    }
+}
+
+#[allow(dead_code)]
+fn print_path(graph: &Graph<(usize, usize), usize>, path: &HashMap<NodeId, (NodeId, usize)>, end_node: NodeId) {
+    let mut edges = Vec::new();
+    let mut cursor = end_node;
+    loop {
+        let (prev_node, dist) = path.get(&cursor).unwrap();
+        edges.push((*prev_node, cursor, *dist));
+        if *prev_node == cursor {
+            break;
+        }
+        cursor = *prev_node;
+    }
+    for (source_node, dest_node, dist) in edges.iter().rev() {
+        trace!("{:?} to {:?} - {}", graph.label(*source_node), graph.label(*dest_node), dist);
+    }
+}
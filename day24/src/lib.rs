@@ -0,0 +1,193 @@
+//! Day 24: Never Tell Me The Odds
+//!
+//! Works out where hailstones' paths cross, and the throw of a rock that hits every hailstone
+
+#![warn(missing_docs)]
+
+use std::ops::{Add, Sub, Div, Mul};
+use fraction::{GenericFraction, ToPrimitive};
+use common::{debug, example, example::Example, params::{self, Param, Params}, parse, Answer, ParseError, Solution, SolveError};
+
+/// Solution for day 24, for use with the `aoc` runner
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u32 = 24;
+    const PARAMS: &'static [Param] = &[
+        Param { name: "min", description: "lower bound of the test area on X and Y in part 1, default 200000000000000", is_valid: params::parses_as::<f64> },
+        Param { name: "max", description: "upper bound of the test area on X and Y in part 1, default 400000000000000", is_valid: params::parses_as::<f64> }
+    ];
+    const EXAMPLES: &'static [Example] = &[example!("example")];
+    type Input = Vec<Hailstone>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(hailstones: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(hailstones).into());
+    }
+
+    fn part2(hailstones: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(hailstones).into());
+    }
+
+    fn part1_with(hailstones: &Self::Input, params: &Params) -> Result<Answer, SolveError> {
+        return Ok(count_collisions_in_area(hailstones, params.get_or("min", MIN_POS), params.get_or("max", MAX_POS)).into());
+    }
+}
+
+/// Lower bound of the test area on X and Y in part 1
+pub const MIN_POS: f64 = 200000000000000.0;
+/// Upper bound of the test area on X and Y in part 1
+pub const MAX_POS: f64 = 400000000000000.0;
+
+// Hailstones needed for part 2's 4 equations, each of which pairs the first hailstone with another
+const MIN_HAILSTONES: usize = 5;
+
+type F128 = GenericFraction<u128>;
+
+struct Vector<T: Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Default + Copy> { // Generic 3D vector for numeric types
+    x: T,
+    y: T,
+    z: T
+}
+
+/// A hailstone at time 0
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Hailstone {
+    /// Position as (x, y, z)
+    pub pos: (i64, i64, i64),
+    /// Velocity per nanosecond as (x, y, z)
+    pub vel: (i64, i64, i64)
+}
+
+/// Reads one `px, py, pz @ vx, vy, vz` hailstone per line
+/// There must be at least 5 hailstones, as part 2 finds the rock's throw from the first 5
+pub fn parse(input: &str) -> Result<Vec<Hailstone>, ParseError> {
+    fn parse_triple(span: parse::Span) -> Result<(i64, i64, i64), ParseError> {
+        let nums = span.split(",");
+        if nums.len() != 3 {
+            return Err(span.error("3 comma-separated numbers"));
+        }
+        return Ok((nums[0].trim().parse()?, nums[1].trim().parse()?, nums[2].trim().parse()?));
+    }
+
+    let mut hailstones = Vec::new();
+    for ln in parse::lines(Day24::DAY, input) {
+        let (pos_str, vel_str) = ln.split_once("@")?;
+        hailstones.push(Hailstone { pos: parse_triple(pos_str)?, vel: parse_triple(vel_str)? });
+    }
+    if hailstones.len() < MIN_HAILSTONES {
+        return Err(parse::end_of_input(Day24::DAY, input, &format!("at least {MIN_HAILSTONES} hailstones")));
+    }
+    return Ok(hailstones);
+}
+
+/// Counts the pairs of hailstones whose future paths cross inside the test area 2e14..=4e14 on X and Y, ignoring Z
+pub fn part1(hailstones: &Vec<Hailstone>) -> usize {
+    return count_collisions_in_area(hailstones, MIN_POS, MAX_POS);
+}
+
+/// Counts pairs of hailstones whose future paths cross within `min_pos..=max_pos` on both X and Y, ignoring Z
+pub fn count_collisions_in_area(hailstones: &Vec<Hailstone>, min_pos: f64, max_pos: f64) -> usize {
+    let hailstones: Vec<(Vector<f64>, Vector<f64>)> = hailstones.iter().map(|h| {
+        return (
+            Vector { x: h.pos.0 as f64, y: h.pos.1 as f64, z: h.pos.2 as f64 },
+            Vector { x: h.vel.0 as f64, y: h.vel.1 as f64, z: h.vel.2 as f64 }
+        )
+    }).collect();
+    //println!("{:?}", hailstones);
+
+    // Check collisions
+    let mut num_collisions = 0;
+    for i in 0..hailstones.len() {
+        for j in (i + 1)..hailstones.len() {
+            let (h1_pos, h1_vel) = &hailstones[i];
+            let (h2_pos, h2_vel) = &hailstones[j];
+
+            // Solve system of equations p_x1 + v_x1 * t_1 = p_x2 + v_x2 * t_2, p_y1 + v_y1 * t_1 = p_y2 + v_y2 * t_2
+            let inv_const = (h1_vel.x * -h2_vel.y) - -(h2_vel.x * h1_vel.y);
+            if inv_const == 0.0 {
+                // If inv_const is 0, then the velocities are collinear
+                // Assume that input does not contain collinear paths that do collide, since the collision point would be indeterminate
+                continue;
+            }
+            let rhs = (h2_pos.x - h1_pos.x, h2_pos.y - h1_pos.y);
+            let t1 = (-h2_vel.y * rhs.0 + h2_vel.x * rhs.1) / inv_const;
+            let t2 = (-h1_vel.y * rhs.0 + h1_vel.x * rhs.1) / inv_const;
+
+            let (collision_x, collision_y) = (h1_pos.x + h1_vel.x * t1, h1_pos.y + h1_vel.y * t1);
+            //println!("Hailstones {}, {} at ({}, {}) at t = ({}, {})", i, j, collision_x, collision_y, t1, t2);
+            if (t1 >= 0.0 && t2 >= 0.0) && (collision_x >= min_pos && collision_x <= max_pos) && (collision_y >= min_pos && collision_y <= max_pos) {
+                num_collisions += 1;
+            }
+        }
+    }
+    return num_collisions;
+}
+
+/// Sums the coordinates of the starting position of a rock thrown in a straight line that hits every hailstone
+pub fn part2(hailstones: &Vec<Hailstone>) -> i128 {
+    let hailstones: Vec<(Vector<F128>, Vector<F128>)> = hailstones.iter().map(|h| {
+        return (
+            Vector { x: F128::from(h.pos.0), y: F128::from(h.pos.1), z: F128::from(h.pos.2) },
+            Vector { x: F128::from(h.vel.0), y: F128::from(h.vel.1), z: F128::from(h.vel.2) }
+        )
+    }).collect();
+
+    // Solve a system of linear equations to determine rock_p_x, rock_p_y, rock_v_x, rock_v_y
+    // Perform Gaussian elimination using fraction objects to avoid precision errors
+    let mut m = [[F128::from(0); 4]; 4];
+    let mut b = [F128::from(0); 4];
+    let (hail_p0, hail_v0) = &hailstones[0];
+    for i in 0..4 {
+        let (hail_p, hail_v) = &hailstones[i + 1];
+        m[i][0] = F128::from(hail_v0.y - hail_v.y);
+        m[i][1] = F128::from(hail_v.x - hail_v0.x);
+        m[i][2] = F128::from(hail_p.y - hail_p0.y);
+        m[i][3] = F128::from(hail_p0.x - hail_p.x);
+        b[i] = F128::from((hail_p0.x * hail_v0.y - hail_p0.y * hail_v0.x) - (hail_p.x * hail_v.y - hail_p.y * hail_v.x));
+    }
+    for i in 0..4 { // Forward elimination
+        // Multiply row by ratio which makes first coefficient equal to 1
+        let ratio = m[i][i].recip();
+        //let ratio = 1.0 / m[i][i];
+        for j in i..4 {
+            m[i][j] *= ratio;
+        }
+        b[i] *= ratio;
+
+        // Set values below diagonal to 0
+        for i_next in (i + 1)..4 {
+            let mult_ratio = m[i_next][i];
+            for j in i..4 {
+                m[i_next][j] -= mult_ratio * m[i][j];
+            }
+            b[i_next] -= mult_ratio * b[i];
+        }
+    }
+    for i in (1..4).rev() { // Back substitution
+        for i_next in (0..i).rev() {
+            b[i_next] -= m[i_next][i] * b[i];
+            m[i_next][i] -= m[i_next][i] * m[i][i];
+        }
+    }
+
+    // Compute rock_p_z and rock_v_z from other values
+    let rock_p_x = b[0];
+    let rock_p_y = b[1];
+    let rock_v_x = b[2];
+    let rock_v_y = b[3];
+    
+    let (p1, v1) = &hailstones[1];
+    let (p2, v2) = &hailstones[2];
+    let t1 = (rock_p_x - p1.x) / (v1.x - rock_v_x);
+    let t2 = (rock_p_x - p2.x) / (v2.x - rock_v_x);
+    let rock_v_z = (p1.z + v1.z * t1 - p2.z - v2.z * t2) / (t1 - t2);
+    let rock_p_z = (p1.z + v1.z * t1) - rock_v_z * t1;
+
+    debug!("Pos: ({}, {}, {})", rock_p_x.to_i128().unwrap(), rock_p_y.to_i128().unwrap(), rock_p_z.to_i128().unwrap());
+    debug!("Vel: ({}, {}, {})", rock_v_x.to_i128().unwrap(), rock_v_y.to_i128().unwrap(), rock_v_z.to_i128().unwrap());
+    return (rock_p_x + rock_p_y + rock_p_z).to_i128().unwrap();   
+}
@@ -0,0 +1,77 @@
+//! Day 25: Snowverload
+//!
+//! Splits a wiring diagram in two by cutting three wires
+
+#![warn(missing_docs)]
+
+use common::{example, example::Example, log, parse, trace, Answer, ParseError, Solution, SolveError};
+use graph::{Graph, NodeId};
+
+/// Solution for day 25, for use with the `aoc` runner
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u32 = 25;
+    const EXAMPLES: &'static [Example] = &[example!("example-part1")];
+    type Input = Graph<String, u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(graph: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part1(graph).into());
+    }
+
+    fn part2(graph: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(part2(graph).into());
+    }
+}
+
+/// Reads the wiring diagram as an undirected graph of components, where each wire has a capacity of 1
+pub fn parse(input: &str) -> Result<Graph<String, u64>, ParseError> {
+    let mut graph = Graph::undirected();
+    for ln in parse::lines(Day25::DAY, input) {
+        let (n, dest) = ln.split_once(": ")?;
+        for name in [n].into_iter().chain(dest.split(" ")) {
+            if name.is_empty() {
+                return Err(name.error("a component name"));
+            }
+            name.check_chars(|c| c.is_ascii_lowercase(), "a lowercase letter")?;
+        }
+        let source = graph.add_node(String::from(n.as_str()));
+        for d in dest.split(" ") {
+            let dest = graph.add_node(String::from(d.as_str()));
+            graph.add_edge(source, dest, 1);
+        }
+    }
+    return Ok(graph);
+}
+
+/// Multiplies the sizes of the two groups left after cutting the three wires that separate them
+/// The wires are found as a minimum cut, from the maximum flow between a fixed start and each other component
+pub fn part1(graph: &Graph<String, u64>) -> usize {
+    // When drawn with Graphviz, the three wires stand out between the two groups
+    if log::enabled(log::Level::Trace) {
+        trace!("{}", graph.to_dot());
+    }
+
+    // Pick one start node, loop over all other nodes as the end node until one is found with a cut set of 3
+    let start_node = NodeId(0);
+    for end_node in graph.nodes().skip(1) {
+        let max_flow = graph::edmonds_karp(graph, start_node, end_node);
+
+        // A flow of 3 saturates exactly 3 cut edges, and the nodes the start can still reach form its group
+        if max_flow.value() == 3 {
+            let num_reachable = max_flow.min_cut().source_side.len();
+            return num_reachable * (graph.num_nodes() - num_reachable);
+        }
+    }
+
+    return 0;
+}
+
+/// Day 25 has no second puzzle, so this is always 0
+pub fn part2(_graph: &Graph<String, u64>) -> usize {
+    return 0;
+}
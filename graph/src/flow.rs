@@ -0,0 +1,190 @@
+// Maximum flows between two nodes, where each edge's weight is its capacity, and the minimum cuts they give
+// An undirected edge can carry flow either way, up to its capacity, and a directed edge only along its direction
+
+use std::{collections::VecDeque, hash::Hash, ops::{Add, Sub}};
+use crate::{Graph, NodeId};
+
+// Edge weights that can be used as capacities, where the default value is no capacity
+pub trait Capacity: Copy + Ord + Default + Add<Output = Self> + Sub<Output = Self> {}
+
+impl<T: Copy + Ord + Default + Add<Output = T> + Sub<Output = T>> Capacity for T {}
+
+// A maximum flow through a graph, kept as the capacity left on each arc
+pub struct MaxFlow<'a, N, W> {
+    graph: &'a Graph<N, W>,
+    source: NodeId,
+    value: W,
+    residual: Vec<W>
+}
+
+// The edges whose removal separates the sink from the source, with the nodes left on the source's side
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MinCut {
+    pub source_side: Vec<NodeId>,
+    pub cut_edges: Vec<usize>
+}
+
+impl<'a, N: Clone + Eq + Hash, W: Capacity> MaxFlow<'a, N, W> {
+    fn new(graph: &'a Graph<N, W>, source: NodeId) -> MaxFlow<'a, N, W> {
+        let mut residual = Vec::with_capacity(2 * graph.edges.len());
+        for edge in graph.edges.iter() {
+            residual.push(edge.weight);
+            residual.push(if graph.directed { W::default() } else { edge.weight });
+        }
+        return MaxFlow { graph: graph, source: source, value: W::default(), residual: residual };
+    }
+
+    // Sends flow along an arc, which frees the same capacity on the arc going back
+    fn push(&mut self, arc: usize, amount: W) {
+        self.residual[arc] = self.residual[arc] - amount;
+        self.residual[arc ^ 1] = self.residual[arc ^ 1] + amount;
+    }
+
+    fn has_capacity(&self, arc: usize) -> bool {
+        return self.residual[arc] > W::default();
+    }
+
+    // Total flow from the source to the sink
+    pub fn value(&self) -> W {
+        return self.value;
+    }
+
+    // Every edge that carries flow, as the nodes it flows from and to and how much flows
+    pub fn flows(&self) -> Vec<(NodeId, NodeId, W)> {
+        let mut flows = Vec::new();
+        for (i, edge) in self.graph.edges.iter().enumerate() {
+            let forward = self.residual[2 * i];
+            if forward < edge.weight {
+                flows.push((edge.from, edge.to, edge.weight - forward));
+            }
+            else if edge.weight < forward {
+                flows.push((edge.to, edge.from, forward - edge.weight));
+            }
+        }
+        return flows;
+    }
+
+    // The nodes the source can still send flow to make up its side of a minimum cut, and the cut edges are those
+    // leaving that side, whose capacities add up to the flow's value
+    pub fn min_cut(&self) -> MinCut {
+        let mut on_source_side = vec![false; self.graph.num_nodes()];
+        on_source_side[self.source.0] = true;
+        let mut source_side = vec![self.source];
+        let mut queue = VecDeque::from([self.source]);
+        while let Some(node) = queue.pop_front() {
+            for arc in self.graph.arcs[node.0].iter() {
+                let next = self.graph.arc_head(*arc);
+                if self.has_capacity(*arc) && !on_source_side[next.0] {
+                    on_source_side[next.0] = true;
+                    source_side.push(next);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let cut_edges = self.graph.edges.iter().enumerate().filter(|(_, edge)| {
+            return on_source_side[edge.from.0] != on_source_side[edge.to.0] && (!self.graph.directed || on_source_side[edge.from.0]);
+        }).map(|(i, _)| i).collect();
+        return MinCut { source_side: source_side, cut_edges: cut_edges };
+    }
+}
+
+// Maximum flow from source to sink with the Edmonds-Karp algorithm, which keeps sending flow along the shortest path
+// that still has capacity, found with BFS
+pub fn edmonds_karp<N: Clone + Eq + Hash, W: Capacity>(graph: &Graph<N, W>, source: NodeId, sink: NodeId) -> MaxFlow<'_, N, W> {
+    let mut flow = MaxFlow::new(graph, source);
+    if source == sink {
+        return flow;
+    }
+    loop {
+        // Find shortest path using BFS, keeping the arc each node was reached along
+        let mut pred_arcs = vec![None; graph.num_nodes()];
+        let mut bfs_queue = VecDeque::from([source]);
+        while let Some(node) = bfs_queue.pop_front() {
+            if node == sink {
+                break;
+            }
+            for arc in graph.arcs[node.0].iter() {
+                let next = graph.arc_head(*arc);
+                if flow.has_capacity(*arc) && next != source && pred_arcs[next.0].is_none() {
+                    pred_arcs[next.0] = Some(*arc);
+                    bfs_queue.push_back(next);
+                }
+            }
+        }
+        if pred_arcs[sink.0].is_none() { // No path to the sink, max flow found
+            break;
+        }
+
+        // Send as much as the path's narrowest arc allows
+        let mut path = Vec::new();
+        let mut cursor = sink;
+        while let Some(arc) = pred_arcs[cursor.0] {
+            path.push(arc);
+            cursor = graph.arc_head(arc ^ 1);
+        }
+        let amount = path.iter().map(|arc| flow.residual[*arc]).min().unwrap();
+        for arc in path {
+            flow.push(arc, amount);
+        }
+        flow.value = flow.value + amount;
+    }
+    return flow;
+}
+
+// Maximum flow from source to sink with Dinic's algorithm, which sends flow in rounds, each along every path that
+// only moves away from the source in a BFS of the arcs with capacity left, until no path reaches the sink
+pub fn dinic<N: Clone + Eq + Hash, W: Capacity>(graph: &Graph<N, W>, source: NodeId, sink: NodeId) -> MaxFlow<'_, N, W> {
+    let mut flow = MaxFlow::new(graph, source);
+    if source == sink {
+        return flow;
+    }
+    loop {
+        // Number the nodes by their distance from the source
+        let mut levels = vec![usize::MAX; graph.num_nodes()];
+        levels[source.0] = 0;
+        let mut bfs_queue = VecDeque::from([source]);
+        while let Some(node) = bfs_queue.pop_front() {
+            for arc in graph.arcs[node.0].iter() {
+                let next = graph.arc_head(*arc);
+                if flow.has_capacity(*arc) && levels[next.0] == usize::MAX {
+                    levels[next.0] = levels[node.0] + 1;
+                    bfs_queue.push_back(next);
+                }
+            }
+        }
+        if levels[sink.0] == usize::MAX {
+            break;
+        }
+
+        // Send flow along paths through the levels until they are blocked, skipping arcs once they are of no use
+        let mut next_arcs = vec![0; graph.num_nodes()];
+        while let Some(amount) = send_through_levels(&mut flow, source, sink, None, &levels, &mut next_arcs) {
+            flow.value = flow.value + amount;
+        }
+    }
+    return flow;
+}
+
+// Sends flow from node to the sink along one path through the levels, limited by the narrowest arc before node if
+// there is one, and returns how much was sent
+fn send_through_levels<N: Clone + Eq + Hash, W: Capacity>(flow: &mut MaxFlow<N, W>, node: NodeId, sink: NodeId, limit: Option<W>,
+                                                          levels: &Vec<usize>, next_arcs: &mut Vec<usize>) -> Option<W> {
+    if node == sink {
+        return limit;
+    }
+    let graph = flow.graph;
+    while next_arcs[node.0] < graph.arcs[node.0].len() {
+        let arc = graph.arcs[node.0][next_arcs[node.0]];
+        let next = graph.arc_head(arc);
+        if flow.has_capacity(arc) && levels[next.0] == levels[node.0] + 1 {
+            let arc_limit = limit.map_or(flow.residual[arc], |l| l.min(flow.residual[arc]));
+            if let Some(amount) = send_through_levels(flow, next, sink, Some(arc_limit), levels, next_arcs) {
+                flow.push(arc, amount);
+                return Some(amount);
+            }
+        }
+        next_arcs[node.0] += 1;
+    }
+    return None;
+}
@@ -0,0 +1,141 @@
+// Graphs of labelled nodes joined by weighted edges, shared by the days that search or cut networks
+
+pub mod flow;
+
+use std::{collections::{HashMap, VecDeque}, fmt::{self, Write}, hash::Hash};
+
+pub use flow::{dinic, edmonds_karp, MaxFlow, MinCut};
+
+// A node of a graph, numbered from 0 in the order the nodes were added
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(pub usize);
+
+// An edge of an undirected graph joins from and to both ways, and is kept in the order it was added
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Edge<W> {
+    pub from: NodeId,
+    pub to: NodeId,
+    pub weight: W
+}
+
+// Nodes are interned, so adding a label that is already in the graph gives back the same node
+// Each edge is stored once, with an arc for each way along it: arc 2 * i goes from edge i's from node to its to
+// node, and arc 2 * i + 1 goes back, which only undirected graphs can travel but flows use as residual capacity
+#[derive(Clone, Debug)]
+pub struct Graph<N, W> {
+    directed: bool,
+    labels: Vec<N>,
+    ids: HashMap<N, NodeId>,
+    edges: Vec<Edge<W>>,
+    arcs: Vec<Vec<usize>>
+}
+
+impl<N: Clone + Eq + Hash, W: Copy> Graph<N, W> {
+    pub fn directed() -> Graph<N, W> {
+        return Graph { directed: true, labels: Vec::new(), ids: HashMap::new(), edges: Vec::new(), arcs: Vec::new() };
+    }
+
+    pub fn undirected() -> Graph<N, W> {
+        return Graph { directed: false, ..Graph::directed() };
+    }
+
+    pub fn is_directed(&self) -> bool {
+        return self.directed;
+    }
+
+    // The node with the label, added if the graph does not have it yet
+    pub fn add_node(&mut self, label: N) -> NodeId {
+        if let Some(id) = self.ids.get(&label) {
+            return *id;
+        }
+        let id = NodeId(self.labels.len());
+        self.labels.push(label.clone());
+        self.ids.insert(label, id);
+        self.arcs.push(Vec::new());
+        return id;
+    }
+
+    // Adds an edge between two nodes, even if they are already joined, and returns its index
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, weight: W) -> usize {
+        let index = self.edges.len();
+        self.edges.push(Edge { from: from, to: to, weight: weight });
+        self.arcs[from.0].push(2 * index);
+        self.arcs[to.0].push(2 * index + 1);
+        return index;
+    }
+
+    pub fn id(&self, label: &N) -> Option<NodeId> {
+        return self.ids.get(label).copied();
+    }
+
+    pub fn label(&self, id: NodeId) -> &N {
+        return &self.labels[id.0];
+    }
+
+    pub fn num_nodes(&self) -> usize {
+        return self.labels.len();
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> {
+        return (0..self.labels.len()).map(NodeId);
+    }
+
+    pub fn edges(&self) -> &[Edge<W>] {
+        return &self.edges;
+    }
+
+    // Nodes reachable in one step along an edge, with the edge's weight, in the order the edges were added
+    pub fn neighbors(&self, id: NodeId) -> impl Iterator<Item = (NodeId, W)> + '_ {
+        return self.arcs[id.0].iter().filter(|arc| !self.directed || arc.is_multiple_of(2))
+                                     .map(|arc| (self.arc_head(*arc), self.edges[arc / 2].weight));
+    }
+
+    fn arc_head(&self, arc: usize) -> NodeId {
+        let edge = &self.edges[arc / 2];
+        return if arc.is_multiple_of(2) { edge.to } else { edge.from };
+    }
+
+    // Groups of nodes joined by paths, ignoring the direction of the edges, each listed from its lowest node in
+    // breadth-first order, and ordered by their lowest nodes
+    pub fn components(&self) -> Vec<Vec<NodeId>> {
+        let mut in_component = vec![false; self.num_nodes()];
+        let mut components = Vec::new();
+        for start in self.nodes() {
+            if in_component[start.0] {
+                continue;
+            }
+            let mut component = vec![start];
+            in_component[start.0] = true;
+            let mut queue = VecDeque::from([start]);
+            while let Some(node) = queue.pop_front() {
+                for arc in self.arcs[node.0].iter() {
+                    let next = self.arc_head(*arc);
+                    if !in_component[next.0] {
+                        in_component[next.0] = true;
+                        component.push(next);
+                        queue.push_back(next);
+                    }
+                }
+            }
+            components.push(component);
+        }
+        return components;
+    }
+}
+
+impl<N: Clone + Eq + Hash + fmt::Display, W: Copy + fmt::Display> Graph<N, W> {
+    // The graph in Graphviz's DOT language, with the labels as node names and the weights as edge labels
+    pub fn to_dot(&self) -> String {
+        let (keyword, edge_op) = if self.directed { ("digraph", "->") } else { ("graph", "--") };
+        let mut dot = format!("{keyword} {{\n");
+        for label in self.labels.iter() {
+            writeln!(dot, "    {:?};", label.to_string()).unwrap();
+        }
+        for edge in self.edges.iter() {
+            let (from, to) = (self.label(edge.from).to_string(), self.label(edge.to).to_string());
+            writeln!(dot, "    {from:?} {edge_op} {to:?} [label={:?}];", edge.weight.to_string()).unwrap();
+        }
+        dot.push_str("}\n");
+        return dot;
+    }
+}